@@ -1,9 +1,6 @@
-use std::io::{BufRead, Seek, SeekFrom};
-
-use crate::{
-    util::{read_u16, read_u32, Endian},
-    ImageResult, ImageSize,
-};
+use crate::io::{ImageReader, SeekFrom};
+use crate::ImageResult;
+use crate::ImageSize;
 
 /// Compression formats for ATC containers
 ///
@@ -20,7 +17,10 @@ pub enum AtcCompression {
     Unknown,
 }
 
-pub fn size<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
+/// Generic over [`ImageReader`] rather than `std::io::{BufRead, Seek}`, so
+/// this parser works the same way over a `no_std` byte cursor as it does
+/// over a file.
+pub fn size<R: ImageReader>(reader: &mut R) -> ImageResult<ImageSize> {
     // ATC files typically use DDS container format
     // But also can be in PKM format or custom ATC format
 
@@ -31,8 +31,8 @@ pub fn size<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
     if header == *b"DDS " {
         // DDS format - seek to dimensions
         reader.seek(SeekFrom::Start(12))?;
-        let height = read_u32(reader, &Endian::Little)? as usize;
-        let width = read_u32(reader, &Endian::Little)? as usize;
+        let height = read_u32_le(reader)? as usize;
+        let width = read_u32_le(reader)? as usize;
         return Ok(ImageSize { width, height });
     }
 
@@ -48,22 +48,32 @@ pub fn size<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
         let data_type = u16::from_be_bytes([pkm_header[6], pkm_header[7]]);
         if matches!(data_type, 0x8C92 | 0x8C93 | 0x87EE) {
             // ATC_RGB, ATC_RGBA_EXPLICIT_ALPHA, and ATC_RGBA_INTERPOLATED_ALPHA
-            reader.seek(SeekFrom::Start(8))?; // Skip magic + version + data type
-            let _extended_width = read_u16(reader, &Endian::Big)?;
-            let _extended_height = read_u16(reader, &Endian::Big)?;
-            let width = read_u16(reader, &Endian::Big)? as usize;
-            let height = read_u16(reader, &Endian::Big)? as usize;
+            reader.seek(SeekFrom::Start(12))?; // Skip magic + version + data type + extended width/height
+            let width = read_u16_be(reader)? as usize;
+            let height = read_u16_be(reader)? as usize;
             return Ok(ImageSize { width, height });
         }
     }
 
     // Fallback: assume basic ATC dimensions at a standard location
     reader.seek(SeekFrom::Start(4))?;
-    let height = read_u32(reader, &Endian::Little)? as usize;
-    let width = read_u32(reader, &Endian::Little)? as usize;
+    let height = read_u32_le(reader)? as usize;
+    let width = read_u32_le(reader)? as usize;
     Ok(ImageSize { width, height })
 }
 
+fn read_u32_le<R: ImageReader>(reader: &mut R) -> ImageResult<u32> {
+    let mut bytes = [0u8; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u16_be<R: ImageReader>(reader: &mut R) -> ImageResult<u16> {
+    let mut bytes = [0u8; 2];
+    reader.read_exact(&mut bytes)?;
+    Ok(u16::from_be_bytes(bytes))
+}
+
 pub fn matches(header: &[u8]) -> bool {
     // Only check for PKM format with ATC data types
     // DDS files with ATC compression should be handled by the DDS format detector
@@ -78,7 +88,7 @@ pub fn matches(header: &[u8]) -> bool {
     false
 }
 
-pub fn detect_compression<R: BufRead + Seek>(reader: &mut R) -> ImageResult<AtcCompression> {
+pub fn detect_compression<R: ImageReader>(reader: &mut R) -> ImageResult<AtcCompression> {
     // Check if it's a PKM format first
     let mut header = [0u8; 8];
     reader.seek(SeekFrom::Start(0))?;
@@ -98,9 +108,30 @@ pub fn detect_compression<R: BufRead + Seek>(reader: &mut R) -> ImageResult<AtcC
 
     // Check if it's DDS format
     if header[0..4] == *b"DDS " {
-        // For DDS, we'd need to check the pixel format section for ATC FourCC
-        // This is a more complex check that would examine the DDS pixel format
-        return Ok(AtcCompression::Unknown); // Default for DDS-contained ATC
+        // The DDS header is 124 bytes starting after the 4-byte magic, so
+        // `DDS_PIXELFORMAT` begins at offset 80 and its FourCC is the u32 at
+        // offset 84.
+        reader.seek(SeekFrom::Start(84))?;
+        let mut fourcc = [0u8; 4];
+        reader.read_exact(&mut fourcc)?;
+
+        return Ok(match &fourcc {
+            b"ATC " => AtcCompression::Rgb,
+            b"ATCA" => AtcCompression::RgbaExplicit,
+            b"ATCI" => AtcCompression::RgbaInterpolated,
+            // DXT1-5/DX10 are standard BC formats, not ATC - recognized
+            // here only so they're reported as a known-but-different
+            // format rather than silently falling through.
+            b"DXT1" | b"DXT2" | b"DXT3" | b"DXT4" | b"DXT5" => AtcCompression::Unknown,
+            b"DX10" => {
+                // No DXGI format corresponds to ATC, but the extended
+                // header is read anyway for parity with the DDS reader.
+                reader.seek(SeekFrom::Start(128))?;
+                let _dxgi_format = read_u32_le(reader)?;
+                AtcCompression::Unknown
+            }
+            _ => AtcCompression::Unknown,
+        });
     }
 
     Ok(AtcCompression::Unknown)
@@ -44,6 +44,148 @@ pub fn matches(header: &[u8]) -> bool {
     header.starts_with(b"DDS ")
 }
 
+/// Returns width/height plus the depth/mipmap/array/face counts carried by
+/// the DDS header, the DX10 extended header (when present), and the cubemap
+/// face flags in `dwCaps2`.
+pub fn container_metadata<R: BufRead + Seek>(
+    reader: &mut R,
+) -> ImageResult<crate::ContainerMetadata> {
+    reader.seek(SeekFrom::Start(12))?;
+    let height = read_u32(reader, &Endian::Little)? as usize;
+    let width = read_u32(reader, &Endian::Little)? as usize;
+
+    reader.seek(SeekFrom::Start(24))?;
+    let depth = read_u32(reader, &Endian::Little)? as usize;
+    let mip_map_count = read_u32(reader, &Endian::Little)? as usize;
+
+    // dwCaps2 (offset 112) carries the cubemap flag and one flag per face.
+    const DDSCAPS2_CUBEMAP: u32 = 0x200;
+    const CUBEMAP_FACE_FLAGS: [u32; 6] = [0x400, 0x800, 0x1000, 0x2000, 0x4000, 0x8000];
+
+    reader.seek(SeekFrom::Start(112))?;
+    let caps2 = read_u32(reader, &Endian::Little)?;
+    let faces = if caps2 & DDSCAPS2_CUBEMAP != 0 {
+        CUBEMAP_FACE_FLAGS
+            .iter()
+            .filter(|&&flag| caps2 & flag != 0)
+            .count()
+            .max(1)
+    } else {
+        1
+    };
+
+    reader.seek(SeekFrom::Start(84))?;
+    let mut fourcc = [0u8; 4];
+    reader.read_exact(&mut fourcc)?;
+
+    const D3D10_RESOURCE_MISC_TEXTURECUBE: u32 = 0x4;
+
+    let (array_layers, dx10_is_cubemap) = if &fourcc == b"DX10" {
+        // miscFlag sits at offset 8, arraySize at offset 12, into the DX10
+        // extended header, which starts right after the main 128-byte DDS
+        // header.
+        reader.seek(SeekFrom::Start(128 + 8))?;
+        let misc_flag = read_u32(reader, &Endian::Little)?;
+
+        reader.seek(SeekFrom::Start(128 + 12))?;
+        let array_size = read_u32(reader, &Endian::Little)? as usize;
+
+        (
+            array_size,
+            misc_flag & D3D10_RESOURCE_MISC_TEXTURECUBE != 0,
+        )
+    } else {
+        (1, false)
+    };
+
+    // Legacy DDS files signal a cubemap through `dwCaps2`; DX10 files can
+    // also carry the flag in the extended header's `miscFlag` instead.
+    let faces = if dx10_is_cubemap { faces.max(6) } else { faces };
+
+    let compression = detect_compression(reader)?;
+
+    Ok(crate::ContainerMetadata {
+        width,
+        height,
+        depth: depth.max(1),
+        mip_levels: mip_map_count.max(1),
+        array_layers: array_layers.max(1),
+        faces,
+        image_type: crate::ImageType::Dds(compression),
+    })
+}
+
+/// Richer DDS metadata beyond width/height/compression: mip levels, volume
+/// depth, cubemap faces and array size, resolved from the main header's
+/// `dwCaps2` flags and (when present) the DX10 extended header.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DdsMetadata {
+    pub width: usize,
+    pub height: usize,
+    pub depth: usize,
+    pub mipmap_count: usize,
+    pub array_size: usize,
+    pub is_cubemap: bool,
+    pub compression: DdsCompression,
+}
+
+/// Reads width/height/depth/mipmap count, the cubemap flag, and (for DX10
+/// files) the array size, returning them alongside the detected compression.
+pub fn metadata<R: BufRead + Seek>(reader: &mut R) -> ImageResult<DdsMetadata> {
+    reader.seek(SeekFrom::Start(12))?;
+    let height = read_u32(reader, &Endian::Little)? as usize;
+    let width = read_u32(reader, &Endian::Little)? as usize;
+
+    reader.seek(SeekFrom::Start(24))?;
+    let depth = read_u32(reader, &Endian::Little)? as usize;
+    let mip_map_count = read_u32(reader, &Endian::Little)? as usize;
+
+    const DDSCAPS2_CUBEMAP: u32 = 0x200;
+    const DDSCAPS2_VOLUME: u32 = 0x200000;
+    const D3D10_RESOURCE_DIMENSION_TEXTURE3D: u32 = 4;
+
+    reader.seek(SeekFrom::Start(112))?;
+    let caps2 = read_u32(reader, &Endian::Little)?;
+    let mut is_cubemap = caps2 & DDSCAPS2_CUBEMAP != 0;
+    let mut is_volume = caps2 & DDSCAPS2_VOLUME != 0;
+
+    reader.seek(SeekFrom::Start(84))?;
+    let mut fourcc = [0u8; 4];
+    reader.read_exact(&mut fourcc)?;
+
+    const D3D10_RESOURCE_MISC_TEXTURECUBE: u32 = 0x4;
+
+    let array_size = if &fourcc == b"DX10" {
+        // resourceDimension sits at offset 4, miscFlag at offset 8, arraySize
+        // at offset 12, into the DX10 extended header, which starts right
+        // after the main 128-byte DDS header.
+        reader.seek(SeekFrom::Start(128 + 4))?;
+        let resource_dimension = read_u32(reader, &Endian::Little)?;
+        is_volume = resource_dimension == D3D10_RESOURCE_DIMENSION_TEXTURE3D;
+
+        reader.seek(SeekFrom::Start(128 + 8))?;
+        let misc_flag = read_u32(reader, &Endian::Little)?;
+        is_cubemap = is_cubemap || misc_flag & D3D10_RESOURCE_MISC_TEXTURECUBE != 0;
+
+        reader.seek(SeekFrom::Start(128 + 12))?;
+        read_u32(reader, &Endian::Little)? as usize
+    } else {
+        1
+    };
+
+    let compression = detect_compression(reader)?;
+
+    Ok(DdsMetadata {
+        width,
+        height,
+        depth: if is_volume { depth.max(1) } else { 1 },
+        mipmap_count: mip_map_count.max(1),
+        array_size: array_size.max(1),
+        is_cubemap,
+        compression,
+    })
+}
+
 pub fn detect_compression<R: BufRead + Seek>(reader: &mut R) -> ImageResult<DdsCompression> {
     // DDS header structure:
     // Signature: "DDS " (4 bytes)
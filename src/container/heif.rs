@@ -1,9 +1,21 @@
 use crate::util::*;
-use crate::{ImageError, ImageResult, ImageSize};
+use crate::{ImageError, ImageResult, ImageSize, Orientation};
 
+use std::collections::HashMap;
 use std::convert::TryInto;
 use std::io::{BufRead, Seek, SeekFrom};
 
+/// Reads a big-endian unsigned integer of exactly `size` bytes (0-8), the
+/// way ISOBMFF boxes store fields whose width depends on a sibling flag
+/// (`iloc`'s `offset_size`/`length_size`/`base_offset_size`/`index_size`).
+fn read_uint<R: BufRead + Seek>(reader: &mut R, size: u8) -> ImageResult<u64> {
+    let mut value = 0u64;
+    for _ in 0..size {
+        value = (value << 8) | read_u8(reader)? as u64;
+    }
+    Ok(value)
+}
+
 // REFS: https://github.com/strukturag/libheif/blob/f0c1a863cabbccb2d280515b7ecc73e6717702dc/libheif/heif.h#L600
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Compression {
@@ -17,7 +29,26 @@ pub enum Compression {
     // Evc,
 }
 
-pub fn size<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
+//  Reads a FullBox's 4-byte version+flags header, returning just the
+//  version; every caller here only branches on version, never on flags
+//  (except `ipma`, which reads flags itself for its 1-vs-2-byte indices).
+fn read_fullbox_version<R: BufRead + Seek>(reader: &mut R) -> ImageResult<u8> {
+    let version = read_u8(reader)?;
+    read_u24(reader, &Endian::Big)?; //  Discard flags
+    Ok(version)
+}
+
+//  Walks the `meta` box's direct children (`pitm`, `iinf`/`infe`, `iprp`
+//  (`ipco`/`ipma`), `iloc`) to find the `ispe` (and, for grid items, the
+//  assembled canvas size) that belongs to the *primary* item, along with any
+//  `irot` rotation associated with it. Falls back to the largest `ispe` seen
+//  if the file has no `pitm` (non-compliant, but seen in the wild), the way
+//  this function used to behave unconditionally. Bounded by `limits` so a
+//  crafted box tree can't force unbounded work.
+fn read_ispe_and_rotation<R: BufRead + Seek>(
+    reader: &mut R,
+    limits: &crate::Limits,
+) -> ImageResult<(ImageSize, u8, Option<u64>)> {
     reader.seek(SeekFrom::Start(0))?;
     //  Read the ftyp header size
     let ftyp_size = read_u32(reader, &Endian::Big)?;
@@ -26,67 +57,408 @@ pub fn size<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
     reader.seek(SeekFrom::Start(ftyp_size.into()))?;
 
     //  Skip to meta tag which contains all the metadata
-    skip_to_tag(reader, b"meta")?;
-    read_u32(reader, &Endian::Big)?; //  Meta has a junk value after it
-    skip_to_tag(reader, b"iprp")?; //  Find iprp tag
+    let meta_size = skip_to_tag(reader, b"meta", limits)?;
+    let meta_content_start = reader.seek(SeekFrom::Current(0))?;
+    let meta_end = meta_content_start - 8 + meta_size;
+    read_u32(reader, &Endian::Big)?; //  Meta has a junk value after it (FullBox version+flags)
 
-    let mut ipco_size = skip_to_tag(reader, b"ipco")? as usize; //  Find ipco tag
+    let mut primary_item_id: Option<u64> = None;
+    let mut item_types: HashMap<u64, String> = HashMap::new();
+    let mut item_properties: HashMap<u64, Vec<u64>> = HashMap::new();
+    let mut ispe_properties: HashMap<u64, (usize, usize)> = HashMap::new();
+    let mut irot_properties: HashMap<u64, u8> = HashMap::new();
+    let mut item_file_offsets: HashMap<u64, u64> = HashMap::new();
+    let mut entries = 0u64;
 
-    //  Keep track of the max size of ipco tag
-    let mut max_width = 0usize;
-    let mut max_height = 0usize;
-    let mut found_ispe = false;
-    let mut rotation = 0u8;
+    while reader.seek(SeekFrom::Current(0))? < meta_end {
+        entries += 1;
+        if entries > limits.max_entries {
+            return Err(ImageError::LimitsExceeded);
+        }
 
-    while let Ok((tag, size)) = read_tag(reader) {
-        //  Size of tag length + tag cannot be under 8 (4 bytes each)
+        let box_start = reader.seek(SeekFrom::Current(0))?;
+        let (tag, size) = read_tag(reader)?;
         if size < 8 {
             return Err(ImageError::CorruptedImage);
         }
+        let box_end = box_start + size;
 
-        //  ispe tag has a junk value followed by width and height as u32
-        if tag == "ispe" {
-            found_ispe = true;
-            read_u32(reader, &Endian::Big)?; //  Discard junk value
-            let width = read_u32(reader, &Endian::Big)? as usize;
-            let height = read_u32(reader, &Endian::Big)? as usize;
-
-            //  Assign new largest size by area
-            if width * height > max_width * max_height {
-                max_width = width;
-                max_height = height;
+        match tag.as_str() {
+            "pitm" => {
+                let version = read_fullbox_version(reader)?;
+                primary_item_id = Some(if version == 0 {
+                    read_u16(reader, &Endian::Big)? as u64
+                } else {
+                    read_u32(reader, &Endian::Big)? as u64
+                });
             }
-        } else if tag == "irot" {
-            // irot is 9 bytes total: size, tag, 1 byte for rotation (0-3)
-            rotation = read_u8(reader)?;
-        } else if size >= ipco_size {
-            // If we've gone past the ipco boundary, then break
-            break;
-        } else {
-            // If we're still inside ipco, consume all bytes for
-            // the current tag, minus the bytes already read in `read_tag`
-            ipco_size -= size;
-            reader.seek(SeekFrom::Current(size as i64 - 8))?;
+            "iinf" => {
+                let version = read_fullbox_version(reader)?;
+                let entry_count = if version == 0 {
+                    read_u16(reader, &Endian::Big)? as u64
+                } else {
+                    read_u32(reader, &Endian::Big)? as u64
+                };
+
+                for _ in 0..entry_count {
+                    entries += 1;
+                    if entries > limits.max_entries {
+                        return Err(ImageError::LimitsExceeded);
+                    }
+
+                    let infe_start = reader.seek(SeekFrom::Current(0))?;
+                    let (infe_tag, infe_size) = read_tag(reader)?;
+                    let infe_end = infe_start + infe_size;
+
+                    if infe_tag == "infe" {
+                        let infe_version = read_fullbox_version(reader)?;
+                        let item_id = if infe_version == 0 || infe_version == 1 {
+                            read_u16(reader, &Endian::Big)? as u64
+                        } else {
+                            read_u32(reader, &Endian::Big)? as u64
+                        };
+                        read_u16(reader, &Endian::Big)?; //  item_protection_index
+
+                        //  Only version >= 2 stores item_type as a plain FourCC;
+                        //  older infe versions use string fields we don't need.
+                        if infe_version >= 2 {
+                            let mut item_type = [0; 4];
+                            reader.read_exact(&mut item_type)?;
+                            item_types
+                                .insert(item_id, String::from_utf8_lossy(&item_type).into_owned());
+                        }
+                    }
+
+                    reader.seek(SeekFrom::Start(infe_end))?;
+                }
+            }
+            "iprp" => {
+                while reader.seek(SeekFrom::Current(0))? < box_end {
+                    entries += 1;
+                    if entries > limits.max_entries {
+                        return Err(ImageError::LimitsExceeded);
+                    }
+
+                    let child_start = reader.seek(SeekFrom::Current(0))?;
+                    let (child_tag, child_size) = read_tag(reader)?;
+                    let child_end = child_start + child_size;
+
+                    match child_tag.as_str() {
+                        "ipco" => {
+                            let mut property_index = 0u64;
+
+                            while reader.seek(SeekFrom::Current(0))? < child_end {
+                                entries += 1;
+                                if entries > limits.max_entries {
+                                    return Err(ImageError::LimitsExceeded);
+                                }
+
+                                let prop_start = reader.seek(SeekFrom::Current(0))?;
+                                let (prop_tag, prop_size) = read_tag(reader)?;
+                                let prop_end = prop_start + prop_size;
+                                property_index += 1;
+
+                                match prop_tag.as_str() {
+                                    "ispe" => {
+                                        read_u32(reader, &Endian::Big)?; //  Discard junk value
+                                        let width = read_u32(reader, &Endian::Big)? as usize;
+                                        let height = read_u32(reader, &Endian::Big)? as usize;
+                                        ispe_properties.insert(property_index, (width, height));
+                                    }
+                                    "irot" => {
+                                        let rotation = read_u8(reader)?;
+                                        irot_properties.insert(property_index, rotation & 0x3);
+                                    }
+                                    _ => (),
+                                }
+
+                                reader.seek(SeekFrom::Start(prop_end))?;
+                            }
+                        }
+                        "ipma" => {
+                            let version = read_u8(reader)?;
+                            let flags = read_u24(reader, &Endian::Big)?;
+                            let entry_count = read_u32(reader, &Endian::Big)?;
+
+                            for _ in 0..entry_count {
+                                entries += 1;
+                                if entries > limits.max_entries {
+                                    return Err(ImageError::LimitsExceeded);
+                                }
+
+                                let item_id = if version == 0 {
+                                    read_u16(reader, &Endian::Big)? as u64
+                                } else {
+                                    read_u32(reader, &Endian::Big)? as u64
+                                };
+                                let association_count = read_u8(reader)?;
+                                let indices = item_properties.entry(item_id).or_default();
+
+                                for _ in 0..association_count {
+                                    let index = if flags & 1 != 0 {
+                                        (read_u16(reader, &Endian::Big)? & 0x7FFF) as u64
+                                    } else {
+                                        (read_u8(reader)? & 0x7F) as u64
+                                    };
+                                    indices.push(index);
+                                }
+                            }
+                        }
+                        _ => (),
+                    }
+
+                    reader.seek(SeekFrom::Start(child_end))?;
+                }
+            }
+            "iloc" => {
+                let version = read_fullbox_version(reader)?;
+                let sizes_byte = read_u8(reader)?;
+                let offset_size = sizes_byte >> 4;
+                let length_size = sizes_byte & 0xF;
+                let base_sizes_byte = read_u8(reader)?;
+                let base_offset_size = base_sizes_byte >> 4;
+                let index_size = if version == 1 || version == 2 {
+                    base_sizes_byte & 0xF
+                } else {
+                    0
+                };
+                let item_count = if version < 2 {
+                    read_u16(reader, &Endian::Big)? as u64
+                } else {
+                    read_u32(reader, &Endian::Big)? as u64
+                };
+
+                for _ in 0..item_count {
+                    entries += 1;
+                    if entries > limits.max_entries {
+                        return Err(ImageError::LimitsExceeded);
+                    }
+
+                    let item_id = if version < 2 {
+                        read_u16(reader, &Endian::Big)? as u64
+                    } else {
+                        read_u32(reader, &Endian::Big)? as u64
+                    };
+                    let construction_method = if version == 1 || version == 2 {
+                        read_u16(reader, &Endian::Big)? & 0xF
+                    } else {
+                        0
+                    };
+                    read_u16(reader, &Endian::Big)?; //  data_reference_index
+                    let base_offset = read_uint(reader, base_offset_size)?;
+                    let extent_count = read_u16(reader, &Endian::Big)?;
+
+                    let mut first_extent = None;
+                    for extent_index in 0..extent_count {
+                        if (version == 1 || version == 2) && index_size > 0 {
+                            read_uint(reader, index_size)?;
+                        }
+                        let extent_offset = read_uint(reader, offset_size)?;
+                        let _extent_length = read_uint(reader, length_size)?;
+
+                        if extent_index == 0 {
+                            first_extent = Some(base_offset + extent_offset);
+                        }
+                    }
+
+                    //  Only plain file-offset items (construction_method 0) are
+                    //  resolvable without also parsing `idat`/other items.
+                    if construction_method == 0 {
+                        if let Some(offset) = first_extent {
+                            item_file_offsets.insert(item_id, offset);
+                        }
+                    }
+                }
+            }
+            _ => (),
+        }
+
+        reader.seek(SeekFrom::Start(box_end))?;
+    }
+
+    //  A file's `Exif` item (if any) carries a full TIFF/EXIF blob; used as a
+    //  fallback orientation source for files that skip the `irot` transform
+    //  property and only carry orientation the way JPEG does.
+    let exif_item_offset = item_types
+        .iter()
+        .find(|(_, item_type)| item_type.as_str() == "Exif")
+        .and_then(|(item_id, _)| item_file_offsets.get(item_id))
+        .copied();
+
+    //  Files without a `pitm` aren't spec-compliant, but fall back to the
+    //  largest `ispe` seen rather than failing outright.
+    let Some(primary_item_id) = primary_item_id else {
+        return ispe_properties
+            .values()
+            .max_by_key(|(width, height)| width * height)
+            .map(|&(width, height)| (ImageSize { width, height }, 0, exif_item_offset))
+            .ok_or_else(|| {
+                std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "Not enough data").into()
+            });
+    };
+
+    let rotation = item_properties
+        .get(&primary_item_id)
+        .into_iter()
+        .flatten()
+        .find_map(|index| irot_properties.get(index).copied())
+        .unwrap_or(0);
+
+    //  Grid items store their assembled canvas size in their own item data
+    //  (found via `iloc`), not as an `ispe` property.
+    if item_types.get(&primary_item_id).map(String::as_str) == Some("grid") {
+        if let Some(&offset) = item_file_offsets.get(&primary_item_id) {
+            reader.seek(SeekFrom::Start(offset))?;
+            let _version = read_u8(reader)?;
+            let flags = read_u8(reader)?;
+            let field_size_is_32 = flags & 1 != 0;
+            let _rows_minus_one = read_u8(reader)?;
+            let _columns_minus_one = read_u8(reader)?;
+
+            let (width, height) = if field_size_is_32 {
+                (
+                    read_u32(reader, &Endian::Big)? as usize,
+                    read_u32(reader, &Endian::Big)? as usize,
+                )
+            } else {
+                (
+                    read_u16(reader, &Endian::Big)? as usize,
+                    read_u16(reader, &Endian::Big)? as usize,
+                )
+            };
+
+            return Ok((ImageSize { width, height }, rotation, exif_item_offset));
         }
     }
 
-    //  If no ispe found, then we have no actual dimension data to use
-    if !found_ispe {
-        return Err(
-            std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "Not enough data").into(),
-        );
+    if let Some(size) = item_properties
+        .get(&primary_item_id)
+        .into_iter()
+        .flatten()
+        .find_map(|index| ispe_properties.get(index).copied())
+        .map(|(width, height)| ImageSize { width, height })
+    {
+        return Ok((size, rotation, exif_item_offset));
     }
 
+    //  Primary item had no resolvable `ispe`; fall back to the largest one
+    //  seen anywhere in the file rather than failing outright.
+    ispe_properties
+        .values()
+        .max_by_key(|(width, height)| width * height)
+        .map(|&(width, height)| (ImageSize { width, height }, rotation, exif_item_offset))
+        .ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "Not enough data").into()
+        })
+}
+
+pub fn size<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
+    size_with_limits(reader, &crate::Limits::default())
+}
+
+/// Like [`size`], but returns [`ImageError::LimitsExceeded`] instead of
+/// following an unbounded chain of boxes or scanning more `ipco` children
+/// than `limits` allows.
+pub fn size_with_limits<R: BufRead + Seek>(
+    reader: &mut R,
+    limits: &crate::Limits,
+) -> ImageResult<ImageSize> {
+    let (mut size, rotation, _exif_item_offset) = read_ispe_and_rotation(reader, limits)?;
+
     //  Rotation can only be 0-3. 1 and 3 are 90 and 270 degrees respectively (anti-clockwise)
     //  If we have 90 or 270 rotation, flip width and height
     if rotation == 1 || rotation == 3 {
-        std::mem::swap(&mut max_width, &mut max_height);
+        std::mem::swap(&mut size.width, &mut size.height);
+    }
+
+    Ok(size)
+}
+
+/// Reads the stored (unrotated) dimensions along with the `Orientation`
+/// derived from the `irot` box, if present, falling back to the `Orientation`
+/// tag in the file's `Exif` item (if any) for files that skip `irot` and only
+/// carry orientation the way JPEG does.
+pub fn size_with_orientation<R: BufRead + Seek>(
+    reader: &mut R,
+) -> ImageResult<(ImageSize, Orientation)> {
+    let (size, rotation, exif_item_offset) =
+        read_ispe_and_rotation(reader, &crate::Limits::default())?;
+
+    if rotation != 0 {
+        return Ok((size, Orientation::from_irot(rotation)));
+    }
+
+    let orientation = exif_item_offset
+        .and_then(|offset| read_exif_item_orientation(reader, offset).ok())
+        .unwrap_or(Orientation::Normal);
+
+    Ok((size, orientation))
+}
+
+/// Reads the `Orientation` tag out of a file's `Exif` item. The item's
+/// payload is a big-endian `exif_tiff_header_offset` followed by that many
+/// bytes (historically the APP1 `Exif\0\0` identifier) before the actual
+/// TIFF header, per the HEIF spec's `ExifDataBlock`.
+fn read_exif_item_orientation<R: BufRead + Seek>(
+    reader: &mut R,
+    item_offset: u64,
+) -> ImageResult<Orientation> {
+    reader.seek(SeekFrom::Start(item_offset))?;
+    let tiff_header_offset = read_u32(reader, &Endian::Big)? as u64;
+    crate::formats::exif::read_orientation(reader, item_offset + 4 + tiff_header_offset)
+}
+
+/// Returns the number of items listed in the `meta` box's `iinf` table,
+/// i.e. how many images (including thumbnails and auxiliary items, not
+/// just the primary one) this HEIF/AVIF file carries.
+pub fn item_count<R: BufRead + Seek>(reader: &mut R) -> ImageResult<usize> {
+    item_count_with_limits(reader, &crate::Limits::default())
+}
+
+/// Like [`item_count`], but returns [`ImageError::LimitsExceeded`] instead
+/// of scanning more boxes than `limits` allows.
+pub fn item_count_with_limits<R: BufRead + Seek>(
+    reader: &mut R,
+    limits: &crate::Limits,
+) -> ImageResult<usize> {
+    reader.seek(SeekFrom::Start(0))?;
+    let ftyp_size = read_u32(reader, &Endian::Big)?;
+    reader.seek(SeekFrom::Start(ftyp_size.into()))?;
+
+    let meta_size = skip_to_tag(reader, b"meta", limits)?;
+    let meta_content_start = reader.seek(SeekFrom::Current(0))?;
+    let meta_end = meta_content_start - 8 + meta_size;
+    read_u32(reader, &Endian::Big)?; //  Meta has a junk value after it (FullBox version+flags)
+
+    let mut entries = 0u64;
+
+    while reader.seek(SeekFrom::Current(0))? < meta_end {
+        entries += 1;
+        if entries > limits.max_entries {
+            return Err(ImageError::LimitsExceeded);
+        }
+
+        let box_start = reader.seek(SeekFrom::Current(0))?;
+        let (tag, size) = read_tag(reader)?;
+        if size < 8 {
+            return Err(ImageError::CorruptedImage);
+        }
+        let box_end = box_start + size;
+
+        if tag == "iinf" {
+            let version = read_fullbox_version(reader)?;
+            let entry_count = if version == 0 {
+                read_u16(reader, &Endian::Big)? as u64
+            } else {
+                read_u32(reader, &Endian::Big)? as u64
+            };
+            return Ok(entry_count as usize);
+        }
+
+        reader.seek(SeekFrom::Start(box_end))?;
     }
 
-    Ok(ImageSize {
-        width: max_width,
-        height: max_height,
-    })
+    Err(std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "No iinf box found").into())
 }
 
 pub fn matches<R: BufRead + Seek>(header: &[u8], reader: &mut R) -> Option<Compression> {
@@ -173,19 +545,32 @@ fn inner_matches(brand: &[u8; 4]) -> Option<Compression> {
     None
 }
 
-fn skip_to_tag<R: BufRead + Seek>(reader: &mut R, tag: &[u8]) -> ImageResult<u32> {
-    let mut tag_buf = [0; 4];
+//  Uses `read_tag` rather than a raw size+type read so a `largesize`
+//  (size `1`, 64-bit size after the type) or to-end-of-file (size `0`) box
+//  is skipped correctly instead of being mistaken for an 8-byte-or-smaller
+//  box.
+fn skip_to_tag<R: BufRead + Seek>(
+    reader: &mut R,
+    tag: &[u8],
+    limits: &crate::Limits,
+) -> ImageResult<u64> {
+    let mut boxes_skipped = 0u64;
 
     loop {
-        let size = read_u32(reader, &Endian::Big)?;
-        reader.read_exact(&mut tag_buf)?;
+        let box_start = reader.seek(SeekFrom::Current(0))?;
+        let (box_tag, size) = read_tag(reader)?;
 
-        if tag_buf == tag {
+        if box_tag.as_bytes() == tag {
             return Ok(size);
         }
 
+        boxes_skipped += 1;
+        if boxes_skipped > limits.max_directories {
+            return Err(ImageError::LimitsExceeded);
+        }
+
         if size >= 8 {
-            reader.seek(SeekFrom::Current(size as i64 - 8))?;
+            reader.seek(SeekFrom::Start(box_start + size))?;
         } else {
             return Err(std::io::Error::new(
                 std::io::ErrorKind::InvalidData,
@@ -1,9 +1,5 @@
-use std::io::{BufRead, Seek, SeekFrom};
-
-use crate::{
-    util::{read_u16, Endian},
-    ImageResult, ImageSize,
-};
+use crate::io::{ImageReader, SeekFrom};
+use crate::{ImageResult, ImageSize};
 
 /// Compression formats for PKM containers (ETC/EAC family)
 ///
@@ -31,7 +27,10 @@ pub enum PkmCompression {
     Unknown,
 }
 
-pub fn size<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
+/// Generic over [`ImageReader`] rather than `std::io::{BufRead, Seek}`, so
+/// this parser works the same way over a `no_std` byte cursor as it does
+/// over a file.
+pub fn size<R: ImageReader>(reader: &mut R) -> ImageResult<ImageSize> {
     // ETC/EAC files are typically in PKM format
     // PKM header structure:
     // Magic: "PKM " (4 bytes)
@@ -42,11 +41,15 @@ pub fn size<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
     // Original width: 2 bytes (big-endian)
     // Original height: 2 bytes (big-endian)
 
-    reader.seek(SeekFrom::Start(8))?; // Skip magic + version + data type
-    let _extended_width = read_u16(reader, &Endian::Big)?;
-    let _extended_height = read_u16(reader, &Endian::Big)?;
-    let width = read_u16(reader, &Endian::Big)? as usize;
-    let height = read_u16(reader, &Endian::Big)? as usize;
+    reader.seek(SeekFrom::Start(12))?; // Skip magic + version + data type + extended width/height
+
+    let mut width_bytes = [0u8; 2];
+    reader.read_exact(&mut width_bytes)?;
+    let width = u16::from_be_bytes(width_bytes) as usize;
+
+    let mut height_bytes = [0u8; 2];
+    reader.read_exact(&mut height_bytes)?;
+    let height = u16::from_be_bytes(height_bytes) as usize;
 
     Ok(ImageSize { width, height })
 }
@@ -73,10 +76,12 @@ pub fn matches_eac(header: &[u8]) -> bool {
     false
 }
 
-pub fn detect_compression<R: BufRead + Seek>(reader: &mut R) -> ImageResult<PkmCompression> {
+pub fn detect_compression<R: ImageReader>(reader: &mut R) -> ImageResult<PkmCompression> {
     // Read the data type from PKM header to determine compression format
     reader.seek(SeekFrom::Start(6))?; // Skip magic and version
-    let data_type = read_u16(reader, &Endian::Big)?;
+    let mut data_type_bytes = [0u8; 2];
+    reader.read_exact(&mut data_type_bytes)?;
+    let data_type = u16::from_be_bytes(data_type_bytes);
 
     let compression = match data_type {
         // ETC1 formats
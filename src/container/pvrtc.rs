@@ -1,9 +1,5 @@
-use std::io::{BufRead, Seek, SeekFrom};
-
-use crate::{
-    util::{read_u32, read_u64, Endian},
-    ImageResult, ImageSize,
-};
+use crate::io::{ImageReader, SeekFrom};
+use crate::{ImageResult, ImageSize};
 
 /// Compression formats for PVRTC containers
 ///
@@ -21,7 +17,7 @@ pub enum PvrtcCompression {
     Pvrtc4BppRgba,
     /// ETC2 RGB compression
     Etc2Rgb,
-    /// ETC2 RGBA compression  
+    /// ETC2 RGBA compression
     Etc2Rgba,
     /// ETC2 RGB with 1-bit alpha
     Etc2RgbA1,
@@ -33,7 +29,22 @@ pub enum PvrtcCompression {
     Unknown,
 }
 
-pub fn size<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
+fn read_u32_le<R: ImageReader>(reader: &mut R) -> ImageResult<u32> {
+    let mut bytes = [0u8; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u64_le<R: ImageReader>(reader: &mut R) -> ImageResult<u64> {
+    let mut bytes = [0u8; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+/// Generic over [`ImageReader`] rather than `std::io::{BufRead, Seek}`, so
+/// this parser works the same way over a `no_std` byte cursor as it does
+/// over a file.
+pub fn size<R: ImageReader>(reader: &mut R) -> ImageResult<ImageSize> {
     // Check if this is PVR v3 format or legacy format
     reader.seek(SeekFrom::Start(0))?;
     let mut magic = [0u8; 4];
@@ -51,8 +62,8 @@ pub fn size<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
         // 32-35: Depth
         // ... rest of header
         reader.seek(SeekFrom::Start(24))?;
-        let height = read_u32(reader, &Endian::Little)? as usize;
-        let width = read_u32(reader, &Endian::Little)? as usize;
+        let height = read_u32_le(reader)? as usize;
+        let width = read_u32_le(reader)? as usize;
 
         Ok(ImageSize { width, height })
     } else {
@@ -62,8 +73,8 @@ pub fn size<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
         // Width: 4 bytes (little-endian)
         // ... rest of legacy header
         reader.seek(SeekFrom::Start(4))?;
-        let height = read_u32(reader, &Endian::Little)? as usize;
-        let width = read_u32(reader, &Endian::Little)? as usize;
+        let height = read_u32_le(reader)? as usize;
+        let width = read_u32_le(reader)? as usize;
 
         Ok(ImageSize { width, height })
     }
@@ -97,16 +108,76 @@ pub fn matches(header: &[u8]) -> bool {
     false
 }
 
-pub fn detect_compression<R: BufRead + Seek>(reader: &mut R) -> ImageResult<PvrtcCompression> {
+/// Returns width/height plus the depth/mipmap/surface/face counts that PVR
+/// v3 stores past the base dimensions.
+///
+/// Legacy-format files only carry a mipmap count alongside their dimensions,
+/// so `depth`, `array_layers`, and `faces` are reported as `1`.
+pub fn container_metadata<R: ImageReader>(reader: &mut R) -> ImageResult<crate::ContainerMetadata> {
+    reader.seek(SeekFrom::Start(0))?;
+    let mut magic = [0u8; 4];
+    reader.read_exact(&mut magic)?;
+
+    if &magic == b"PVR\x03" {
+        // PVR v3 format structure (continuing from `size` above):
+        // 32-35: Depth
+        // 36-39: Number of surfaces (array layers)
+        // 40-43: Number of faces
+        // 44-47: Number of MIP-map levels
+        reader.seek(SeekFrom::Start(24))?;
+        let height = read_u32_le(reader)? as usize;
+        let width = read_u32_le(reader)? as usize;
+        let depth = read_u32_le(reader)? as usize;
+        let num_surfaces = read_u32_le(reader)? as usize;
+        let num_faces = read_u32_le(reader)? as usize;
+        let num_mipmaps = read_u32_le(reader)? as usize;
+
+        let compression = detect_compression(reader)?;
+
+        Ok(crate::ContainerMetadata {
+            width,
+            height,
+            depth: depth.max(1),
+            mip_levels: num_mipmaps.max(1),
+            array_layers: num_surfaces.max(1),
+            faces: num_faces.max(1),
+            image_type: crate::ImageType::Pvrtc(compression),
+        })
+    } else {
+        // Legacy PVR format structure (continuing from `size` above):
+        // 12-15: MIP-map count
+        reader.seek(SeekFrom::Start(4))?;
+        let height = read_u32_le(reader)? as usize;
+        let width = read_u32_le(reader)? as usize;
+        let num_mipmaps = read_u32_le(reader)? as usize;
+
+        let compression = detect_compression(reader)?;
+
+        Ok(crate::ContainerMetadata {
+            width,
+            height,
+            depth: 1,
+            mip_levels: num_mipmaps.max(1),
+            array_layers: 1,
+            faces: 1,
+            image_type: crate::ImageType::Pvrtc(compression),
+        })
+    }
+}
+
+pub fn detect_compression<R: ImageReader>(reader: &mut R) -> ImageResult<PvrtcCompression> {
     // Check if this is PVR v3 format or legacy format
     reader.seek(SeekFrom::Start(0))?;
     let mut magic = [0u8; 4];
     reader.read_exact(&mut magic)?;
 
     if &magic == b"PVR\x03" {
-        // PVR v3 format - read pixel format from offset 8-15
+        // PVR v3 format - read pixel format from offset 8-15. The "packed"
+        // format IDs this crate recognizes only ever occupy the low word;
+        // the high word is reserved for `pixelType`-style per-channel
+        // encodings that don't apply to any of these.
         reader.seek(SeekFrom::Start(8))?;
-        let pixel_format = read_u64(reader, &Endian::Little)?;
+        let pixel_format = read_u64_le(reader)? & 0xFFFF_FFFF;
 
         let compression = match pixel_format {
             0 => PvrtcCompression::Pvrtc2BppRgb,  // PVRTCI_2BPP_RGB
@@ -1,10 +1,102 @@
-use std::io::{BufRead, Seek, SeekFrom};
-
+use crate::io::{ImageReader, SeekFrom};
 use crate::{ImageResult, ImageSize};
 
-pub fn size<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
-    // ASTC header is 16 bytes total
-    // Magic number: 0x13, 0x12, 0x10, 0x5C (4 bytes)
+/// Block footprint of an ASTC texture, read from the file header.
+///
+/// ASTC's whole point is a selectable block footprint (bandwidth vs. quality
+/// trade-off), so unlike most block-compressed formats it isn't fixed at
+/// 4x4 - it ranges from 4x4 up through 12x12, plus 3D footprints for volume
+/// textures.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AstcCompression {
+    Block4x4,
+    Block5x4,
+    Block5x5,
+    Block6x5,
+    Block6x6,
+    Block8x5,
+    Block8x6,
+    Block8x8,
+    Block10x5,
+    Block10x6,
+    Block10x8,
+    Block10x10,
+    Block12x10,
+    Block12x12,
+    /// A 3D block footprint (`block_z > 1`), used by ASTC volume textures.
+    Block3D { x: u8, y: u8, z: u8 },
+    /// Header declared a footprint this crate doesn't recognize.
+    Unknown,
+}
+
+impl AstcCompression {
+    /// Returns the (x, y) block footprint, ignoring the z dimension for 3D
+    /// footprints - this is what byte-size calculations over a single 2D
+    /// slice need.
+    pub fn block_dimensions(&self) -> Option<(u8, u8)> {
+        match self {
+            AstcCompression::Block4x4 => Some((4, 4)),
+            AstcCompression::Block5x4 => Some((5, 4)),
+            AstcCompression::Block5x5 => Some((5, 5)),
+            AstcCompression::Block6x5 => Some((6, 5)),
+            AstcCompression::Block6x6 => Some((6, 6)),
+            AstcCompression::Block8x5 => Some((8, 5)),
+            AstcCompression::Block8x6 => Some((8, 6)),
+            AstcCompression::Block8x8 => Some((8, 8)),
+            AstcCompression::Block10x5 => Some((10, 5)),
+            AstcCompression::Block10x6 => Some((10, 6)),
+            AstcCompression::Block10x8 => Some((10, 8)),
+            AstcCompression::Block10x10 => Some((10, 10)),
+            AstcCompression::Block12x10 => Some((12, 10)),
+            AstcCompression::Block12x12 => Some((12, 12)),
+            AstcCompression::Block3D { x, y, .. } => Some((*x, *y)),
+            AstcCompression::Unknown => None,
+        }
+    }
+}
+
+fn classify_block(block_x: u8, block_y: u8, block_z: u8) -> AstcCompression {
+    if block_z > 1 {
+        return AstcCompression::Block3D {
+            x: block_x,
+            y: block_y,
+            z: block_z,
+        };
+    }
+
+    match (block_x, block_y) {
+        (4, 4) => AstcCompression::Block4x4,
+        (5, 4) => AstcCompression::Block5x4,
+        (5, 5) => AstcCompression::Block5x5,
+        (6, 5) => AstcCompression::Block6x5,
+        (6, 6) => AstcCompression::Block6x6,
+        (8, 5) => AstcCompression::Block8x5,
+        (8, 6) => AstcCompression::Block8x6,
+        (8, 8) => AstcCompression::Block8x8,
+        (10, 5) => AstcCompression::Block10x5,
+        (10, 6) => AstcCompression::Block10x6,
+        (10, 8) => AstcCompression::Block10x8,
+        (10, 10) => AstcCompression::Block10x10,
+        (12, 10) => AstcCompression::Block12x10,
+        (12, 12) => AstcCompression::Block12x12,
+        _ => AstcCompression::Unknown,
+    }
+}
+
+/// Generic over [`ImageReader`] rather than `std::io::{BufRead, Seek}`, so
+/// this parser works the same way over a `no_std` byte cursor as it does
+/// over a file - `std`'s readers implement `ImageReader` through a blanket
+/// impl, so existing callers don't need to change.
+pub fn size<R: ImageReader>(reader: &mut R) -> ImageResult<ImageSize> {
+    size_3d(reader).map(|(size, _depth)| size)
+}
+
+/// Like [`size`], but also returns the `zsize` field (byte offset 13),
+/// which is 1 for ordinary 2D textures and greater than 1 for 3D/volumetric
+/// ASTC textures.
+pub fn size_3d<R: ImageReader>(reader: &mut R) -> ImageResult<(ImageSize, usize)> {
+    // ASTC header is 16 bytes total:
+    // Magic number: 0x5CA1AB13, little-endian (4 bytes)
     // Block dimensions: blockdim_x, blockdim_y, blockdim_z (3 bytes) - skip these
     // Image dimensions: xsize (3 bytes), ysize (3 bytes), zsize (3 bytes)
 
@@ -19,10 +111,27 @@ pub fn size<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
     reader.read_exact(&mut ysize_bytes[0..3])?;
     let height = u32::from_le_bytes(ysize_bytes) as usize;
 
-    Ok(ImageSize { width, height })
+    let mut zsize_bytes = [0u8; 4];
+    reader.read_exact(&mut zsize_bytes[0..3])?;
+    let depth = (u32::from_le_bytes(zsize_bytes) as usize).max(1);
+
+    Ok((ImageSize { width, height }, depth))
 }
 
 pub fn matches(header: &[u8]) -> bool {
-    // ASTC magic number is 0x13 0xAB 0xA0 0x5C
-    header.len() >= 4 && header[0..4] == [0x13, 0xAB, 0xA0, 0x5C]
+    // ASTC magic number is 0x5CA1AB13, little-endian
+    header.len() >= 4 && header[0..4] == [0x13, 0xAB, 0xA1, 0x5C]
+}
+
+/// Reads the block footprint (blockdim_x, blockdim_y, blockdim_z) from bytes
+/// 4-6 of the header and classifies it into an [`AstcCompression`] variant.
+/// Unlike most compressed texture formats, ASTC doesn't use a fixed 4x4
+/// block size, so the footprint has to be read per-file.
+pub fn detect_compression<R: ImageReader>(reader: &mut R) -> ImageResult<AstcCompression> {
+    reader.seek(SeekFrom::Start(4))?;
+
+    let mut dims = [0u8; 3];
+    reader.read_exact(&mut dims)?;
+
+    Ok(classify_block(dims[0], dims[1], dims[2]))
 }
@@ -0,0 +1,42 @@
+use std::io::{BufRead, Seek, SeekFrom};
+
+use crate::{
+    util::{read_i32, read_u16, read_u32, Endian},
+    ImageResult, ImageSize,
+};
+
+//  OS/2 BITMAPCOREHEADER's size; every later DIB header (BITMAPINFOHEADER
+//  and its V2/V3/V4/V5 successors, sizes 40/52/56/108/124) uses 32-bit
+//  dimension fields instead of this header's 16-bit ones.
+const OS2_CORE_HEADER_SIZE: u32 = 12;
+
+pub fn size<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
+    //  BITMAPFILEHEADER is 14 bytes; the DIB header size immediately follows
+    reader.seek(SeekFrom::Start(14))?;
+    let dib_header_size = read_u32(reader, &Endian::Little)?;
+
+    //  Legacy OS/2 BITMAPCOREHEADER stores unsigned 16-bit dimensions right
+    //  after its own size field. Every later header stores signed 32-bit
+    //  dimensions at the same offset instead; unrecognized header sizes are
+    //  treated as the 32-bit case, since every header newer than
+    //  BITMAPINFOHEADER keeps that layout.
+    let (width, height) = if dib_header_size == OS2_CORE_HEADER_SIZE {
+        let width = read_u16(reader, &Endian::Little)? as i32;
+        let height = read_u16(reader, &Endian::Little)? as i32;
+        (width, height)
+    } else {
+        let width = read_i32(reader, &Endian::Little)?;
+        let height = read_i32(reader, &Endian::Little)?;
+        (width, height)
+    };
+
+    //  Height is negative for top-down bitmaps; the pixel count is the same either way
+    Ok(ImageSize {
+        width: width as usize,
+        height: height.unsigned_abs() as usize,
+    })
+}
+
+pub fn matches(header: &[u8]) -> bool {
+    header.starts_with(b"BM")
+}
@@ -0,0 +1,59 @@
+//! Minimal EXIF parsing shared by the formats that carry an embedded EXIF
+//! TIFF blob (JPEG's APP1 segment and HEIF's `Exif` item). The only thing this crate needs
+//! out of it is the `Orientation` tag, so this isn't a general EXIF reader.
+
+use std::io::{BufRead, Seek, SeekFrom};
+
+use crate::util::{read_u16, read_u32, Endian};
+use crate::{ImageResult, Orientation};
+
+const ORIENTATION_TAG: u16 = 0x0112;
+
+/// Reads the `Orientation` tag (0x0112) out of a TIFF-style IFD, returning
+/// [`Orientation::Normal`] if the tag isn't present.
+///
+/// `tiff_header_start` is the reader position of the IFD's "II"/"MM"
+/// byte-order marker. Per the TIFF spec, the IFD offset and every
+/// offset-valued IFD entry are relative to that position rather than to the
+/// start of whatever container (JPEG APP1 segment, HEIF `Exif` item, ...)
+/// the TIFF header is embedded in.
+pub(crate) fn read_orientation<R: BufRead + Seek>(
+    reader: &mut R,
+    tiff_header_start: u64,
+) -> ImageResult<Orientation> {
+    reader.seek(SeekFrom::Start(tiff_header_start))?;
+
+    let mut endian_marker = [0; 2];
+    reader.read_exact(&mut endian_marker)?;
+    let endianness = match &endian_marker {
+        b"II" => Endian::Little,
+        b"MM" => Endian::Big,
+        _ => return Ok(Orientation::Normal),
+    };
+
+    // Skip the TIFF magic number (always 42/classic TIFF for EXIF).
+    let _magic = read_u16(reader, &endianness)?;
+    let ifd_offset = read_u32(reader, &endianness)? as u64;
+
+    reader.seek(SeekFrom::Start(tiff_header_start + ifd_offset))?;
+    let entry_count = read_u16(reader, &endianness)?;
+
+    for _ in 0..entry_count {
+        let tag = read_u16(reader, &endianness)?;
+        let field_type = read_u16(reader, &endianness)?;
+        let _count = read_u32(reader, &endianness)?;
+        let raw_value = read_u32(reader, &endianness)?;
+
+        // Orientation is always a SHORT (type 3), stored in the first 2
+        // bytes of the 4-byte value field, byte-order dependent.
+        if tag == ORIENTATION_TAG && field_type == 3 {
+            let value = match endianness {
+                Endian::Little => (raw_value & 0xFFFF) as u16,
+                Endian::Big => (raw_value >> 16) as u16,
+            };
+            return Ok(Orientation::from_exif(value));
+        }
+    }
+
+    Ok(Orientation::Normal)
+}
@@ -1,8 +1,8 @@
 use std::io::{self, BufRead, Seek, SeekFrom};
 
 use crate::{
-    util::{read_i32, read_null_terminated_string, read_u32, Endian},
-    ImageResult, ImageSize,
+    util::{read_i32, read_null_terminated_string, read_u32, read_u8, Endian},
+    ChannelLayout, ImageInfo, ImageResult, ImageSize,
 };
 
 pub fn size<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
@@ -40,6 +40,83 @@ pub fn size<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
     Err(io::Error::new(io::ErrorKind::InvalidData, "Data window not found").into())
 }
 
+/// Reads width/height plus the pixel format from the `dataWindow` and
+/// `channels` header attributes: channel count gives the layout, and each
+/// channel's pixel type (0 = UINT, 1 = HALF, 2 = FLOAT) gives the bit depth.
+pub fn image_info<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageInfo> {
+    reader.seek(SeekFrom::Start(8))?;
+
+    let mut width = None;
+    let mut height = None;
+    let mut channel_count = 0usize;
+    let mut pixel_type = None;
+
+    loop {
+        let attr_name = read_null_terminated_string(reader)?;
+        if attr_name.is_empty() {
+            break;
+        }
+
+        let attr_type = read_null_terminated_string(reader)?;
+        let attr_size = read_u32(reader, &Endian::Little)?;
+        let attr_start = reader.stream_position()?;
+
+        if attr_name == "dataWindow" && attr_type == "box2i" {
+            let x_min = read_i32(reader, &Endian::Little)?;
+            let y_min = read_i32(reader, &Endian::Little)?;
+            let x_max = read_i32(reader, &Endian::Little)?;
+            let y_max = read_i32(reader, &Endian::Little)?;
+
+            width = Some((x_max - x_min + 1) as usize);
+            height = Some((y_max - y_min + 1) as usize);
+        } else if attr_name == "channels" && attr_type == "chlist" {
+            loop {
+                let channel_name = read_null_terminated_string(reader)?;
+                if channel_name.is_empty() {
+                    break;
+                }
+
+                let channel_pixel_type = read_i32(reader, &Endian::Little)?;
+                let _p_linear = read_u8(reader)?;
+                reader.seek(SeekFrom::Current(3))?; // reserved
+                let _x_sampling = read_i32(reader, &Endian::Little)?;
+                let _y_sampling = read_i32(reader, &Endian::Little)?;
+
+                channel_count += 1;
+                pixel_type.get_or_insert(channel_pixel_type);
+            }
+        }
+
+        reader.seek(SeekFrom::Start(attr_start + attr_size as u64))?;
+    }
+
+    let (width, height) = match (width, height) {
+        (Some(width), Some(height)) => (width, height),
+        _ => return Err(io::Error::new(io::ErrorKind::InvalidData, "Data window not found").into()),
+    };
+
+    let (bits_per_channel, is_float) = match pixel_type {
+        Some(1) => (16, true),  // HALF
+        Some(2) => (32, true),  // FLOAT
+        _ => (32, false),       // UINT, or no channel info at all
+    };
+
+    let channels = match channel_count {
+        1 => ChannelLayout::Gray,
+        2 => ChannelLayout::GrayAlpha,
+        3 => ChannelLayout::Rgb,
+        _ => ChannelLayout::Rgba,
+    };
+
+    Ok(ImageInfo {
+        width,
+        height,
+        bits_per_channel,
+        channels,
+        is_float,
+    })
+}
+
 pub fn matches(header: &[u8]) -> bool {
     let exr_magic_number = [0x76, 0x2f, 0x31, 0x01];
     header.starts_with(&exr_magic_number)
@@ -1,3 +1,6 @@
+use std::io::{BufRead, Seek, SeekFrom};
+
+use crate::util::read_u8;
 use crate::{ImageResult, ImageSize};
 
 pub fn size(header: &[u8]) -> ImageResult<ImageSize> {
@@ -10,3 +13,121 @@ pub fn size(header: &[u8]) -> ImageResult<ImageSize> {
 pub fn matches(header: &[u8]) -> bool {
     header.starts_with(b"GIF8")
 }
+
+//  Both extension blocks and image data are followed by a stream of
+//  length-prefixed sub-blocks, terminated by a zero-length one.
+fn skip_sub_blocks<R: BufRead + Seek>(reader: &mut R) -> ImageResult<()> {
+    loop {
+        let len = read_u8(reader)?;
+        if len == 0 {
+            break;
+        }
+        reader.seek(SeekFrom::Current(len as i64))?;
+    }
+    Ok(())
+}
+
+/// Counts the Image Descriptor blocks (`0x2C`) in a GIF, i.e. the number of
+/// animation frames, by walking the block stream after the logical screen
+/// descriptor and skipping extension blocks (`0x21`) along the way.
+pub fn frame_count<R: BufRead + Seek>(reader: &mut R) -> ImageResult<usize> {
+    reader.seek(SeekFrom::Start(6))?;
+
+    let mut screen_descriptor = [0u8; 7];
+    reader.read_exact(&mut screen_descriptor)?;
+
+    if screen_descriptor[4] & 0x80 != 0 {
+        let table_size = 3 * (2usize << (screen_descriptor[4] & 0x07));
+        reader.seek(SeekFrom::Current(table_size as i64))?;
+    }
+
+    let mut frames = 0usize;
+
+    loop {
+        let block_type = match read_u8(reader) {
+            Ok(b) => b,
+            Err(_) => break,
+        };
+
+        match block_type {
+            0x21 => {
+                read_u8(reader)?; // Extension label
+                skip_sub_blocks(reader)?;
+            }
+            0x2C => {
+                frames += 1;
+
+                let mut descriptor = [0u8; 9];
+                reader.read_exact(&mut descriptor)?;
+
+                if descriptor[8] & 0x80 != 0 {
+                    let table_size = 3 * (2usize << (descriptor[8] & 0x07));
+                    reader.seek(SeekFrom::Current(table_size as i64))?;
+                }
+
+                read_u8(reader)?; // LZW minimum code size
+                skip_sub_blocks(reader)?;
+            }
+            // Trailer, or anything unrecognized - stop rather than loop forever.
+            _ => break,
+        }
+    }
+
+    Ok(frames)
+}
+
+/// Returns the dimensions of every Image Descriptor block (`0x2C`) in a GIF,
+/// i.e. one entry per animation frame, in file order.
+///
+/// Note that each frame's descriptor stores its own width/height (frames can
+/// be smaller than the logical screen and composited at an offset), so these
+/// are the per-frame sizes rather than the logical screen size `size` above
+/// returns.
+pub fn sizes<R: BufRead + Seek>(reader: &mut R) -> ImageResult<Vec<ImageSize>> {
+    reader.seek(SeekFrom::Start(6))?;
+
+    let mut screen_descriptor = [0u8; 7];
+    reader.read_exact(&mut screen_descriptor)?;
+
+    if screen_descriptor[4] & 0x80 != 0 {
+        let table_size = 3 * (2usize << (screen_descriptor[4] & 0x07));
+        reader.seek(SeekFrom::Current(table_size as i64))?;
+    }
+
+    let mut sizes = Vec::new();
+
+    loop {
+        let block_type = match read_u8(reader) {
+            Ok(b) => b,
+            Err(_) => break,
+        };
+
+        match block_type {
+            0x21 => {
+                read_u8(reader)?; // Extension label
+                skip_sub_blocks(reader)?;
+            }
+            0x2C => {
+                let mut descriptor = [0u8; 9];
+                reader.read_exact(&mut descriptor)?;
+
+                sizes.push(ImageSize {
+                    width: (descriptor[4] as usize) | ((descriptor[5] as usize) << 8),
+                    height: (descriptor[6] as usize) | ((descriptor[7] as usize) << 8),
+                });
+
+                if descriptor[8] & 0x80 != 0 {
+                    let table_size = 3 * (2usize << (descriptor[8] & 0x07));
+                    reader.seek(SeekFrom::Current(table_size as i64))?;
+                }
+
+                read_u8(reader)?; // LZW minimum code size
+                skip_sub_blocks(reader)?;
+            }
+            // Trailer, or anything unrecognized - stop rather than loop forever.
+            _ => break,
+        }
+    }
+
+    Ok(sizes)
+}
@@ -0,0 +1,119 @@
+use std::io::{BufRead, Seek, SeekFrom};
+
+use super::exif;
+use crate::util::{read_u16, read_u8, Endian};
+use crate::{ImageResult, ImageSize, Orientation};
+
+// Start Of Frame markers. 0xC4/0xC8/0xCC are reserved/non-SOF and excluded.
+const SOF_MARKERS: [u8; 13] = [
+    0xC0, 0xC1, 0xC2, 0xC3, 0xC5, 0xC6, 0xC7, 0xC9, 0xCA, 0xCB, 0xCD, 0xCE, 0xCF,
+];
+
+const APP1_MARKER: u8 = 0xE1;
+const SOS_MARKER: u8 = 0xDA;
+const EXIF_IDENTIFIER: [u8; 6] = *b"Exif\0\0";
+
+/// Walks the marker segments of a JPEG stream, collecting the first SOF's
+/// dimensions and the `Orientation` found in an `Exif` APP1 segment, if any.
+fn scan<R: BufRead + Seek>(reader: &mut R) -> ImageResult<(ImageSize, Orientation)> {
+    reader.seek(SeekFrom::Start(0))?;
+
+    let mut soi = [0; 2];
+    reader.read_exact(&mut soi)?;
+    if soi != [0xFF, 0xD8] {
+        return Err(
+            std::io::Error::new(std::io::ErrorKind::InvalidData, "Invalid JPEG header").into(),
+        );
+    }
+
+    let mut size = None;
+    let mut orientation = Orientation::Normal;
+
+    loop {
+        // Markers are introduced by 0xFF; some encoders pad with extra
+        // 0xFF fill bytes before the actual marker byte.
+        if read_u8(reader)? != 0xFF {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "Expected JPEG marker",
+            )
+            .into());
+        }
+        let mut marker = read_u8(reader)?;
+        while marker == 0xFF {
+            marker = read_u8(reader)?;
+        }
+
+        match marker {
+            // TEM and RSTn carry no length field and no payload.
+            0x01 | 0xD0..=0xD7 => continue,
+            SOS_MARKER => break,
+            marker if SOF_MARKERS.contains(&marker) => {
+                let _length = read_u16(reader, &Endian::Big)?;
+                let _precision = read_u8(reader)?;
+                let height = read_u16(reader, &Endian::Big)?;
+                let width = read_u16(reader, &Endian::Big)?;
+
+                if size.is_none() {
+                    size = Some(ImageSize {
+                        width: width as usize,
+                        height: height as usize,
+                    });
+                }
+            }
+            APP1_MARKER => {
+                let length = read_u16(reader, &Endian::Big)?;
+                let segment_end =
+                    reader.seek(SeekFrom::Current(0))? + (length as u64).saturating_sub(2);
+
+                let mut identifier = [0; 6];
+                reader.read_exact(&mut identifier)?;
+                if identifier == EXIF_IDENTIFIER {
+                    let tiff_header_start = reader.seek(SeekFrom::Current(0))?;
+                    orientation = exif::read_orientation(reader, tiff_header_start)?;
+                }
+
+                reader.seek(SeekFrom::Start(segment_end))?;
+            }
+            _ => {
+                let length = read_u16(reader, &Endian::Big)?;
+                reader.seek(SeekFrom::Current((length as i64) - 2))?;
+            }
+        }
+    }
+
+    match size {
+        Some(size) => Ok((size, orientation)),
+        None => Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "No SOF marker found in JPEG",
+        )
+        .into()),
+    }
+}
+
+/// Returns the reported dimensions, corrected for `Orientation` when the
+/// EXIF tag calls for a 90/270 degree rotation. Use [`size_raw`] to get the
+/// unrotated dimensions alongside the `Orientation` instead.
+pub fn size<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
+    let (size, orientation) = scan(reader)?;
+
+    Ok(if orientation.swaps_dimensions() {
+        ImageSize {
+            width: size.height,
+            height: size.width,
+        }
+    } else {
+        size
+    })
+}
+
+/// Like [`size`], but returns the dimensions exactly as stored (no rotation
+/// applied) along with the `Orientation` found in the EXIF APP1 segment.
+pub fn size_raw<R: BufRead + Seek>(reader: &mut R) -> ImageResult<(ImageSize, Orientation)> {
+    scan(reader)
+}
+
+pub fn matches(header: &[u8]) -> bool {
+    header.starts_with(&[0xFF, 0xD8])
+}
@@ -0,0 +1,197 @@
+use std::io::{BufRead, Read, Seek, SeekFrom};
+
+use crate::util::read_u32;
+use crate::{ImageError, ImageResult, ImageSize, Orientation};
+
+// Raw codestream signature (no ISOBMFF container).
+const CODESTREAM_SIGNATURE: [u8; 2] = [0xFF, 0x0A];
+
+// The 12-byte "JXL " signature box that starts a container-wrapped file.
+const CONTAINER_SIGNATURE: [u8; 12] = [
+    0x00, 0x00, 0x00, 0x0C, 0x4A, 0x58, 0x4C, 0x20, 0x0D, 0x0A, 0x87, 0x0A,
+];
+
+// Predefined width:height ratios selectable by the 3-bit `ratio` field,
+// indexed by `ratio - 1`.
+const RATIOS: [(u64, u64); 7] = [
+    (1, 1),
+    (12, 10),
+    (4, 3),
+    (3, 2),
+    (16, 9),
+    (5, 4),
+    (2, 1),
+];
+
+/// Reads individual bits out of a byte stream, LSB-first within each byte,
+/// matching the JPEG XL codestream's bit-packing.
+struct BitReader<'a, R> {
+    reader: &'a mut R,
+    bits: u64,
+    count: u32,
+}
+
+impl<'a, R: Read> BitReader<'a, R> {
+    fn new(reader: &'a mut R) -> Self {
+        BitReader {
+            reader,
+            bits: 0,
+            count: 0,
+        }
+    }
+
+    fn read_bits(&mut self, n: u32) -> ImageResult<u64> {
+        while self.count < n {
+            let mut byte = [0u8; 1];
+            self.reader.read_exact(&mut byte)?;
+            self.bits |= (byte[0] as u64) << self.count;
+            self.count += 8;
+        }
+
+        let value = self.bits & ((1u64 << n) - 1);
+        self.bits >>= n;
+        self.count -= n;
+        Ok(value)
+    }
+
+    fn read_bool(&mut self) -> ImageResult<bool> {
+        Ok(self.read_bits(1)? != 0)
+    }
+}
+
+// The JXL "U32" field encoding: a 2-bit selector picks one of four
+// (bit width, offset) pairs used to read the actual value.
+fn read_dimension_field<R: Read>(br: &mut BitReader<R>) -> ImageResult<u64> {
+    match br.read_bits(2)? {
+        0 => br.read_bits(9),
+        1 => Ok(br.read_bits(13)? + 256),
+        2 => Ok(br.read_bits(18)? + 2304),
+        3 => Ok(br.read_bits(30)? + 18688),
+        _ => unreachable!(),
+    }
+}
+
+fn read_dimension<R: Read>(br: &mut BitReader<R>, div8: bool) -> ImageResult<u64> {
+    if div8 {
+        Ok((br.read_bits(5)? + 1) * 8)
+    } else {
+        read_dimension_field(br)
+    }
+}
+
+// Orientation lives in ImageMetadata, right after SizeHeader. It's only
+// present when the header opts out of its defaults via `all_default` and
+// `extra_fields`; otherwise orientation is `Normal`.
+fn read_orientation<R: Read>(br: &mut BitReader<R>) -> ImageResult<Orientation> {
+    let all_default = br.read_bool()?;
+    if all_default {
+        return Ok(Orientation::Normal);
+    }
+
+    let extra_fields = br.read_bool()?;
+    if !extra_fields {
+        return Ok(Orientation::Normal);
+    }
+
+    let orientation = br.read_bits(3)? as u16 + 1;
+    Ok(Orientation::from_exif(orientation))
+}
+
+fn read_codestream_header<R: Read>(reader: &mut R) -> ImageResult<(ImageSize, Orientation)> {
+    let mut br = BitReader::new(reader);
+
+    let div8 = br.read_bool()?;
+    let height = read_dimension(&mut br, div8)?;
+
+    let ratio = br.read_bits(3)?;
+    let width = if ratio == 0 {
+        read_dimension(&mut br, div8)?
+    } else {
+        let (num, den) = RATIOS[(ratio - 1) as usize];
+        height * num / den
+    };
+
+    let orientation = read_orientation(&mut br)?;
+
+    Ok((
+        ImageSize {
+            width: width as usize,
+            height: height as usize,
+        },
+        orientation,
+    ))
+}
+
+// Walks the ISOBMFF-style box container looking for the first codestream
+// box: either a single `jxlc`, or the first `jxlp` chunk (whose payload is
+// prefixed with a 4-byte partial-codestream index we need to skip).
+fn find_codestream<R: BufRead + Seek>(reader: &mut R) -> ImageResult<()> {
+    reader.seek(SeekFrom::Start(0))?;
+
+    loop {
+        let mut size_buf = [0u8; 4];
+        if reader.read_exact(&mut size_buf).is_err() {
+            return Err(ImageError::NotSupported);
+        }
+        let mut size = u32::from_be_bytes(size_buf) as u64;
+
+        let mut box_type = [0u8; 4];
+        reader.read_exact(&mut box_type)?;
+
+        let mut header_len = 8u64;
+        if size == 1 {
+            size = read_u32(reader, &crate::util::Endian::Big)? as u64;
+            header_len = 16;
+        }
+
+        match &box_type {
+            b"jxlc" => return Ok(()),
+            b"jxlp" => {
+                let mut index = [0u8; 4];
+                reader.read_exact(&mut index)?;
+                return Ok(());
+            }
+            _ => {
+                if size < header_len {
+                    return Err(ImageError::CorruptedImage);
+                }
+                reader.seek(SeekFrom::Current((size - header_len) as i64))?;
+            }
+        }
+    }
+}
+
+fn scan<R: BufRead + Seek>(reader: &mut R) -> ImageResult<(ImageSize, Orientation)> {
+    reader.seek(SeekFrom::Start(0))?;
+
+    let mut signature = [0u8; 2];
+    reader.read_exact(&mut signature)?;
+
+    if signature == CODESTREAM_SIGNATURE {
+        return read_codestream_header(reader);
+    }
+
+    find_codestream(reader)?;
+    read_codestream_header(reader)
+}
+
+pub fn size<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
+    let (size, orientation) = scan(reader)?;
+
+    Ok(if orientation.swaps_dimensions() {
+        ImageSize {
+            width: size.height,
+            height: size.width,
+        }
+    } else {
+        size
+    })
+}
+
+pub fn size_raw<R: BufRead + Seek>(reader: &mut R) -> ImageResult<(ImageSize, Orientation)> {
+    scan(reader)
+}
+
+pub fn matches(header: &[u8]) -> bool {
+    header.starts_with(&CODESTREAM_SIGNATURE) || header.starts_with(&CONTAINER_SIGNATURE)
+}
@@ -1,7 +1,61 @@
 use std::io::{self, BufRead, Seek, SeekFrom};
 
+use crate::util::{read_u32, Endian};
 use crate::{ImageResult, ImageSize};
 
+/// Compression format carried by a KTX2 container's `vkFormat` field.
+///
+/// KTX2 can wrap any of several GPU texture compression schemes, so (unlike
+/// simpler single-compression containers) this mirrors `DdsCompression` /
+/// `PvrtcCompression` in exposing the actual compression alongside the
+/// container format.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Ktx2Compression {
+    /// Block Compression 1 (DXT1)
+    Bc1,
+    /// Block Compression 2 (DXT3)
+    Bc2,
+    /// Block Compression 3 (DXT5)
+    Bc3,
+    /// Block Compression 4 (ATI1)
+    Bc4,
+    /// Block Compression 5 (ATI2)
+    Bc5,
+    /// Block Compression 6H - HDR format
+    Bc6h,
+    /// Block Compression 7
+    Bc7,
+    /// Ericsson Texture Compression 2
+    Etc2,
+    /// Ericsson Alpha Compression
+    Eac,
+    /// Adaptive Scalable Texture Compression
+    Astc,
+    /// Other/unrecognized vkFormat
+    Unknown,
+}
+
+/// Maps the `vkFormat` field (a little-endian `u32` right after the 12-byte
+/// identifier) to the compression family it encodes.
+pub fn detect_compression<R: BufRead + Seek>(reader: &mut R) -> ImageResult<Ktx2Compression> {
+    reader.seek(SeekFrom::Start(12))?;
+    let vk_format = read_u32(reader, &Endian::Little)?;
+
+    Ok(match vk_format {
+        131..=134 => Ktx2Compression::Bc1,
+        135..=136 => Ktx2Compression::Bc2,
+        137..=138 => Ktx2Compression::Bc3,
+        139..=140 => Ktx2Compression::Bc4,
+        141..=142 => Ktx2Compression::Bc5,
+        143..=144 => Ktx2Compression::Bc6h,
+        145..=146 => Ktx2Compression::Bc7,
+        147..=152 => Ktx2Compression::Etc2,
+        153..=156 => Ktx2Compression::Eac,
+        157..=184 => Ktx2Compression::Astc,
+        _ => Ktx2Compression::Unknown,
+    })
+}
+
 pub fn size<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
     reader.seek(SeekFrom::Start(0))?;
 
@@ -24,6 +78,48 @@ pub fn size<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
     Ok(ImageSize { width, height })
 }
 
+/// Returns width/height plus the depth/mipmap/layer/face counts stored in
+/// the KTX2 header, continuing on from the fields `size` above already
+/// reads.
+pub fn container_metadata<R: BufRead + Seek>(
+    reader: &mut R,
+) -> ImageResult<crate::ContainerMetadata> {
+    reader.seek(SeekFrom::Start(0))?;
+
+    let mut identifier = [0; 12];
+    reader.read_exact(&mut identifier)?;
+    let ktx2_identifier = [
+        0xAB, 0x4B, 0x54, 0x58, 0x20, 0x32, 0x30, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A,
+    ];
+
+    if identifier != ktx2_identifier {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "Invalid KTX2 identifier").into());
+    }
+
+    let mut header = [0; 40];
+    reader.read_exact(&mut header)?;
+
+    let width = u32::from_le_bytes([header[4], header[5], header[6], header[7]]) as usize;
+    let height = u32::from_le_bytes([header[8], header[9], header[10], header[11]]) as usize;
+    let depth = u32::from_le_bytes([header[12], header[13], header[14], header[15]]) as usize;
+    let layer_count = u32::from_le_bytes([header[16], header[17], header[18], header[19]]) as usize;
+    let face_count = u32::from_le_bytes([header[20], header[21], header[22], header[23]]) as usize;
+    let level_count = u32::from_le_bytes([header[24], header[25], header[26], header[27]]) as usize;
+
+    reader.seek(SeekFrom::Start(12))?;
+    let compression = detect_compression(reader)?;
+
+    Ok(crate::ContainerMetadata {
+        width,
+        height,
+        depth: depth.max(1),
+        mip_levels: level_count.max(1),
+        array_layers: layer_count.max(1),
+        faces: face_count.max(1),
+        image_type: crate::ImageType::Ktx2(compression),
+    })
+}
+
 pub fn matches(header: &[u8]) -> bool {
     let ktx2_identifier = [
         0xAB, 0x4B, 0x54, 0x58, 0x20, 0x32, 0x30, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A,
@@ -4,6 +4,8 @@ pub mod aesprite;
 pub mod astc;
 #[cfg(feature = "bmp")]
 pub mod bmp;
+#[cfg(any(feature = "jpeg", feature = "heif"))]
+pub(crate) mod exif;
 #[cfg(feature = "exr")]
 pub mod exr;
 #[cfg(feature = "farbfeld")]
@@ -24,6 +26,8 @@ pub mod jxl;
 pub mod ktx2;
 #[cfg(feature = "png")]
 pub mod png;
+#[cfg(feature = "pict")]
+pub mod pict;
 #[cfg(feature = "pnm")]
 pub mod pnm;
 #[cfg(feature = "psd")]
@@ -112,7 +116,10 @@ pub fn image_type<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageType> {
 
     #[cfg(feature = "astc")]
     if astc::matches(&header) {
-        return Ok(ImageType::Astc);
+        use astc::AstcCompression;
+
+        let compression = astc::detect_compression(reader).unwrap_or(AstcCompression::Unknown);
+        return Ok(ImageType::Astc(compression));
     }
 
     #[cfg(feature = "atc")]
@@ -173,7 +180,10 @@ pub fn image_type<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageType> {
 
     #[cfg(feature = "ktx2")]
     if ktx2::matches(&header) {
-        return Ok(ImageType::Ktx2);
+        use ktx2::Ktx2Compression;
+
+        let compression = ktx2::detect_compression(reader).unwrap_or(Ktx2Compression::Unknown);
+        return Ok(ImageType::Ktx2(compression));
     }
 
     #[cfg(feature = "qoi")]
@@ -201,6 +211,11 @@ pub fn image_type<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageType> {
         return Ok(ImageType::Ilbm);
     }
 
+    #[cfg(feature = "pict")]
+    if pict::matches(&header, reader) {
+        return Ok(ImageType::Pict);
+    }
+
     // Keep TGA last because it has the highest probability of false positives
     #[cfg(feature = "tga")]
     if tga::matches(&header, reader) {
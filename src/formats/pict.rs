@@ -0,0 +1,70 @@
+use std::io::{BufRead, Seek, SeekFrom};
+
+use crate::util::{read_i16, read_u16, Endian};
+use crate::{ImageResult, ImageSize};
+
+// On-disk PICT files (".pict") carry a 512-byte zero header before picSize;
+// in-memory pictures (e.g. clipboard exports) start right at picSize.
+const DISK_HEADER_SIZE: u64 = 512;
+
+// The version-2 opcode (0x0011) followed by its version number (0x02FF)
+// that immediately follows picFrame in every PICT2 file.
+const VERSION_2_OPCODE: u16 = 0x0011;
+const VERSION_2_NUMBER: u16 = 0x02FF;
+
+// Reads picSize followed by the picFrame bounding rectangle (four
+// big-endian i16 values, top/left/bottom/right), assuming the reader is
+// positioned right at picSize.
+fn read_frame<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
+    let _pic_size = read_u16(reader, &Endian::Big)?;
+
+    let top = read_i16(reader, &Endian::Big)?;
+    let left = read_i16(reader, &Endian::Big)?;
+    let bottom = read_i16(reader, &Endian::Big)?;
+    let right = read_i16(reader, &Endian::Big)?;
+
+    if right <= left || bottom <= top {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "PICT frame rectangle is empty or inverted",
+        )
+        .into());
+    }
+
+    Ok(ImageSize {
+        width: (right - left) as usize,
+        height: (bottom - top) as usize,
+    })
+}
+
+// Returns the byte offset of picSize by checking for the version-2 opcode
+// at the position it would be if picSize started right after the 512-byte
+// disk header, then at offset 0 if that fails.
+fn pic_size_offset<R: BufRead + Seek>(reader: &mut R) -> Option<u64> {
+    for offset in [DISK_HEADER_SIZE, 0] {
+        reader.seek(SeekFrom::Start(offset + 2 + 8)).ok()?;
+
+        let opcode = read_u16(reader, &Endian::Big);
+        let version = read_u16(reader, &Endian::Big);
+
+        if let (Ok(opcode), Ok(version)) = (opcode, version) {
+            if opcode == VERSION_2_OPCODE && version == VERSION_2_NUMBER {
+                return Some(offset);
+            }
+        }
+    }
+
+    None
+}
+
+pub fn size<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
+    let offset = pic_size_offset(reader)
+        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidData, "Not a PICT file"))?;
+
+    reader.seek(SeekFrom::Start(offset))?;
+    read_frame(reader)
+}
+
+pub fn matches<R: BufRead + Seek>(_header: &[u8], reader: &mut R) -> bool {
+    pic_size_offset(reader).is_some()
+}
@@ -4,8 +4,22 @@ use crate::{ImageResult, ImageSize};
 use std::io::{self, BufRead, Seek, SeekFrom};
 
 pub fn size<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
-    reader.seek(SeekFrom::Start(2))?;
+    reader.seek(SeekFrom::Start(0))?;
 
+    let mut magic = [0; 2];
+    reader.read_exact(&mut magic)?;
+
+    match magic[1] {
+        b'1'..=b'6' => size_plain(reader),
+        b'7' => size_pam(reader),
+        b'F' | b'f' => size_pfm(reader),
+        _ => Err(io::Error::new(io::ErrorKind::InvalidData, "Unrecognized PNM signature").into()),
+    }
+}
+
+/// P1-P6: a whitespace-separated `width height` pair, possibly preceded by
+/// `#` comment lines.
+fn size_plain<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
     loop {
         // Lines can be arbitrarily long, but 1k is a good enough cap I think.
         // Anything higher and I blame whoever made the file.
@@ -38,15 +52,75 @@ pub fn size<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
     Err(io::Error::new(io::ErrorKind::InvalidData, "PNM dimensions not found").into())
 }
 
-pub fn matches(header: &[u8]) -> bool {
-    if header[0] != b'P' {
-        return false;
+/// P7 (PAM): a sequence of `KEYWORD value` lines terminated by `ENDHDR`.
+/// `WIDTH`/`HEIGHT` are the only keywords this needs; `DEPTH`, `MAXVAL` and
+/// `TUPLTYPE` are skipped.
+fn size_pam<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
+    let mut width = None;
+    let mut height = None;
+
+    loop {
+        let line = read_line_capped(reader, 256)?;
+        let trimmed_line = line.trim();
+
+        if trimmed_line.is_empty() || trimmed_line.starts_with('#') {
+            continue;
+        }
+
+        if trimmed_line == "ENDHDR" {
+            break;
+        }
+
+        let mut fields = trimmed_line.split_whitespace();
+        match fields.next() {
+            Some("WIDTH") => width = fields.next().and_then(|value| value.parse().ok()),
+            Some("HEIGHT") => height = fields.next().and_then(|value| value.parse().ok()),
+            _ => (),
+        }
+    }
+
+    match (width, height) {
+        (Some(width), Some(height)) => Ok(ImageSize { width, height }),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "PAM header missing WIDTH/HEIGHT",
+        )
+        .into()),
+    }
+}
+
+/// PF (color) / Pf (grayscale) Portable Float Map: a `width height` line
+/// follows the two-byte signature, then a signed scale factor whose sign
+/// gives the sample endianness. The scale isn't needed for dimensions, so
+/// it's left unread.
+fn size_pfm<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
+    // Discards the rest of the signature line (normally just the newline).
+    read_line_capped(reader, 8)?;
+
+    let dimensions_line = read_line_capped(reader, 64)?;
+    let dimensions: Vec<&str> = dimensions_line.trim().split_whitespace().collect();
+
+    if dimensions.len() != 2 {
+        return Err(
+            io::Error::new(io::ErrorKind::InvalidData, "Invalid PFM dimensions line").into(),
+        );
     }
 
-    // We only support P1 to P6. Currently ignoring P7, PF, PFM
-    if header[1] < b'1' && header[1] > b'6' {
+    let width = dimensions[0].parse::<usize>().ok();
+    let height = dimensions[1].parse::<usize>().ok();
+
+    match (width, height) {
+        (Some(width), Some(height)) => Ok(ImageSize { width, height }),
+        _ => Err(
+            io::Error::new(io::ErrorKind::InvalidData, "Invalid PFM dimensions line").into(),
+        ),
+    }
+}
+
+pub fn matches(header: &[u8]) -> bool {
+    if header.len() < 2 || header[0] != b'P' {
         return false;
     }
 
-    true
+    (b'1'..=b'7').contains(&header[1]) || header[1] == b'F' || header[1] == b'f'
 }
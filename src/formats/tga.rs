@@ -1,6 +1,6 @@
 use std::io::{BufRead, Seek, SeekFrom};
 
-use crate::{ImageResult, ImageSize};
+use crate::{ChannelLayout, ImageInfo, ImageResult, ImageSize, Orientation};
 
 pub fn size<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
     reader.seek(SeekFrom::Start(0))?;
@@ -14,6 +14,69 @@ pub fn size<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
     Ok(ImageSize { width, height })
 }
 
+/// Reads width/height plus the `Orientation` encoded in the image
+/// descriptor byte (byte 17): bit 4 set means the pixel origin is on the
+/// right (a horizontal flip), bit 5 set means the origin is at the top (a
+/// vertical flip).
+pub fn size_with_orientation<R: BufRead + Seek>(
+    reader: &mut R,
+) -> ImageResult<(ImageSize, Orientation)> {
+    reader.seek(SeekFrom::Start(0))?;
+
+    let mut header = [0; 18];
+    reader.read_exact(&mut header)?;
+
+    let width = u16::from_le_bytes([header[12], header[13]]) as usize;
+    let height = u16::from_le_bytes([header[14], header[15]]) as usize;
+
+    let image_descriptor = header[17];
+    let origin_right = image_descriptor & 0x10 != 0;
+    let origin_top = image_descriptor & 0x20 != 0;
+
+    let orientation = match (origin_right, origin_top) {
+        (false, false) => Orientation::Normal,
+        (true, false) => Orientation::FlipHorizontal,
+        (false, true) => Orientation::FlipVertical,
+        (true, true) => Orientation::Rotate180,
+    };
+
+    Ok((ImageSize { width, height }, orientation))
+}
+
+/// Reads width/height plus the pixel format straight out of the 18-byte
+/// header: the image type (byte 2) tells us colormapped/grayscale/truecolor,
+/// and the pixel depth (byte 16) gives the bit depth.
+pub fn image_info<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageInfo> {
+    reader.seek(SeekFrom::Start(0))?;
+
+    let mut header = [0; 18];
+    reader.read_exact(&mut header)?;
+
+    let image_type = header[2];
+    let pixel_depth = header[16] as usize;
+
+    let (channels, bits_per_channel) = match image_type {
+        // Colormapped (1) and RLE colormapped (9)
+        1 | 9 => (ChannelLayout::Indexed, pixel_depth as u16),
+        // Grayscale (3) and RLE grayscale (11)
+        3 | 11 => (ChannelLayout::Gray, pixel_depth as u16),
+        // Truecolor (2) and RLE truecolor (10)
+        _ if pixel_depth == 32 => (ChannelLayout::Rgba, 8),
+        _ => (ChannelLayout::Rgb, (pixel_depth / 3).max(1) as u16),
+    };
+
+    let width = u16::from_le_bytes([header[12], header[13]]) as usize;
+    let height = u16::from_le_bytes([header[14], header[15]]) as usize;
+
+    Ok(ImageInfo {
+        width,
+        height,
+        bits_per_channel,
+        channels,
+        is_float: false,
+    })
+}
+
 pub fn matches(header: &[u8]) -> bool {
     // Check the image type (byte 2) to be one of the uncompressed or RLE compressed types
     let image_type = header[2];
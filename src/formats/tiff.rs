@@ -1,5 +1,5 @@
 use crate::util::*;
-use crate::{ImageResult, ImageSize};
+use crate::{ImageResult, ImageSize, Orientation};
 
 use std::io::{BufRead, Cursor, Read, Seek, SeekFrom};
 
@@ -9,9 +9,10 @@ enum Type {
     BigTiff,
 }
 
-pub fn size<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
-    reader.seek(SeekFrom::Start(0))?;
-
+//  Reads the 8 (or 16 for BigTIFF) byte TIFF header and returns the
+//  endianness, flavor, and offset of the first IFD. Assumes the reader is
+//  positioned at the start of the TIFF byte-order marker.
+fn read_header<R: BufRead + Seek>(reader: &mut R) -> ImageResult<(Endian, Type, u64)> {
     let mut endian_marker = [0; 2];
     reader.read_exact(&mut endian_marker)?;
 
@@ -71,6 +72,93 @@ pub fn size<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
         );
     }
 
+    Ok((endianness, tiff_type, ifd_offset))
+}
+
+//  Reads a single IFD entry's tag and, if its type is 2 or 4 bytes wide
+//  (SHORT/SSHORT or LONG/SLONG/FLOAT/IFD), its raw value.
+//  Leaves the reader positioned right after the entry.
+fn read_ifd_entry<R: BufRead + Seek>(
+    reader: &mut R,
+    endianness: &Endian,
+    tiff_type: &Type,
+) -> ImageResult<(u16, Option<u32>)> {
+    let tag = read_u16(reader, endianness)?;
+    let kind = read_u16(reader, endianness)?;
+    let _count = if *tiff_type == Type::Tiff {
+        read_u32(reader, endianness)? as u64
+    } else {
+        read_u64(reader, endianness)?
+    };
+
+    let value_bytes = match kind {
+        // BYTE | ASCII | SBYTE | UNDEFINED
+        1 | 2 | 6 | 7 => 1,
+        // SHORT | SSHORT
+        3 | 8 => 2,
+        // LONG | SLONG | FLOAT | IFD
+        4 | 9 | 11 | 13 => 4,
+        // RATIONAL | SRATIONAL
+        5 | 10 => 4 * 2,
+        // DOUBLE
+        12 => 8,
+        // BigTiff only: LONG8 | SLONG8 | IFD8
+        16..=18 => {
+            if *tiff_type == Type::Tiff {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "Invalid IFD type for standard TIFF",
+                )
+                .into());
+            }
+            8
+        }
+        // Anything else is invalid
+        _ => {
+            return Err(
+                std::io::Error::new(std::io::ErrorKind::InvalidData, "Invalid IFD type").into(),
+            )
+        }
+    };
+
+    let mut value_buffer = [0; 8];
+    let ifd_value_length = if *tiff_type == Type::Tiff { 4 } else { 8 };
+    let mut handle = reader.take(ifd_value_length);
+    let bytes_loaded = handle.read(&mut value_buffer)?;
+    if bytes_loaded != ifd_value_length as usize {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "Invalid IFD value length",
+        )
+        .into());
+    }
+
+    let mut r = Cursor::new(&value_buffer[..]);
+    let value = match value_bytes {
+        2 => Some(read_u16(&mut r, endianness)? as u32),
+        4 => Some(read_u32(&mut r, endianness)?),
+        _ => None,
+    };
+
+    Ok((tag, value))
+}
+
+pub fn size<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
+    size_with_limits(reader, &crate::Limits::default())
+}
+
+/// Like [`size`], but returns [`crate::ImageError::LimitsExceeded`] instead
+/// of scanning an IFD with more entries than `limits.max_entries`. Since
+/// `ifd_count` comes straight from the file (a full `u64` on BigTIFF), this
+/// guards against a crafted header forcing an enormous number of reads.
+pub fn size_with_limits<R: BufRead + Seek>(
+    reader: &mut R,
+    limits: &crate::Limits,
+) -> ImageResult<ImageSize> {
+    reader.seek(SeekFrom::Start(0))?;
+
+    let (endianness, tiff_type, ifd_offset) = read_header(reader)?;
+
     //  Jump to the IFD offset
     reader.seek(SeekFrom::Start(ifd_offset))?;
 
@@ -81,68 +169,15 @@ pub fn size<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
         read_u64(reader, &endianness)?
     };
 
+    if ifd_count > limits.max_entries {
+        return Err(crate::ImageError::LimitsExceeded);
+    }
+
     let mut width = None;
     let mut height = None;
 
     for _ifd in 0..ifd_count {
-        let tag = read_u16(reader, &endianness)?;
-        let kind = read_u16(reader, &endianness)?;
-        let _count = if tiff_type == Type::Tiff {
-            read_u32(reader, &endianness)? as u64
-        } else {
-            read_u64(reader, &endianness)?
-        };
-
-        let value_bytes = match kind {
-            // BYTE | ASCII | SBYTE | UNDEFINED
-            1 | 2 | 6 | 7 => 1,
-            // SHORT | SSHORT
-            3 | 8 => 2,
-            // LONG | SLONG | FLOAT | IFD
-            4 | 9 | 11 | 13 => 4,
-            // RATIONAL | SRATIONAL
-            5 | 10 => 4 * 2,
-            // DOUBLE
-            12 => 8,
-            // BigTiff only: LONG8 | SLONG8 | IFD8
-            16..=18 => {
-                if tiff_type == Type::Tiff {
-                    return Err(std::io::Error::new(
-                        std::io::ErrorKind::InvalidData,
-                        "Invalid IFD type for standard TIFF",
-                    )
-                    .into());
-                }
-                8
-            }
-            // Anything else is invalid
-            _ => {
-                return Err(std::io::Error::new(
-                    std::io::ErrorKind::InvalidData,
-                    "Invalid IFD type",
-                )
-                .into())
-            }
-        };
-
-        let mut value_buffer = [0; 8];
-        let ifd_value_length = if tiff_type == Type::Tiff { 4 } else { 8 };
-        let mut handle = reader.take(ifd_value_length);
-        let bytes_loaded = handle.read(&mut value_buffer)?;
-        if bytes_loaded != ifd_value_length as usize {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::InvalidData,
-                "Invalid IFD value length",
-            )
-            .into());
-        }
-
-        let mut r = Cursor::new(&value_buffer[..]);
-        let value = match value_bytes {
-            2 => Some(read_u16(&mut r, &endianness)? as u32),
-            4 => Some(read_u32(&mut r, &endianness)?),
-            _ => None,
-        };
+        let (tag, value) = read_ifd_entry(reader, &endianness, &tiff_type)?;
 
         //  Tag 0x100 is the image width, 0x101 is image height
         if tag == 0x100 {
@@ -164,8 +199,222 @@ pub fn size<R: BufRead + Seek>(reader: &mut R) -> ImageResult<ImageSize> {
     Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "No dimensions in IFD tags").into())
 }
 
+/// Reads the stored (unrotated) dimensions along with the `Orientation`
+/// found in tag `0x112`, if any. Unlike [`size`], this walks the entire IFD
+/// since orientation (0x112) can appear after the dimension tags.
+pub fn size_with_orientation<R: BufRead + Seek>(
+    reader: &mut R,
+) -> ImageResult<(ImageSize, Orientation)> {
+    reader.seek(SeekFrom::Start(0))?;
+
+    let (endianness, tiff_type, ifd_offset) = read_header(reader)?;
+
+    reader.seek(SeekFrom::Start(ifd_offset))?;
+
+    let ifd_count = if tiff_type == Type::Tiff {
+        read_u16(reader, &endianness)? as u64
+    } else {
+        read_u64(reader, &endianness)?
+    };
+
+    let mut width = None;
+    let mut height = None;
+    let mut orientation = Orientation::Normal;
+
+    for _ifd in 0..ifd_count {
+        let (tag, value) = read_ifd_entry(reader, &endianness, &tiff_type)?;
+
+        match tag {
+            0x100 => width = value,
+            0x101 => height = value,
+            0x112 => {
+                if let Some(value) = value {
+                    orientation = Orientation::from_exif(value as u16);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    match (width, height) {
+        (Some(width), Some(height)) => Ok((
+            ImageSize {
+                width: width as usize,
+                height: height as usize,
+            },
+            orientation,
+        )),
+        _ => Err(
+            std::io::Error::new(std::io::ErrorKind::InvalidData, "No dimensions in IFD tags")
+                .into(),
+        ),
+    }
+}
+
+/// Reads width/height plus the pixel format from `BitsPerSample` (258),
+/// `SamplesPerPixel` (277), and `SampleFormat` (339, where 3 means IEEE
+/// float).
+///
+/// `BitsPerSample`/`SampleFormat` are only trustworthy here when they fit
+/// inline in the IFD entry, which [`read_ifd_entry`] assumes is always the
+/// case. For `SamplesPerPixel > 1` that assumption doesn't hold (the real
+/// value lives at an external offset this lightweight reader doesn't
+/// follow), so multi-sample images report 8-bit integer samples rather than
+/// misreading that offset as a value.
+pub fn image_info<R: BufRead + Seek>(reader: &mut R) -> ImageResult<crate::ImageInfo> {
+    reader.seek(SeekFrom::Start(0))?;
+
+    let (endianness, tiff_type, ifd_offset) = read_header(reader)?;
+    reader.seek(SeekFrom::Start(ifd_offset))?;
+
+    let ifd_count = if tiff_type == Type::Tiff {
+        read_u16(reader, &endianness)? as u64
+    } else {
+        read_u64(reader, &endianness)?
+    };
+
+    let mut width = None;
+    let mut height = None;
+    let mut bits_per_sample = None;
+    let mut samples_per_pixel = None;
+    let mut sample_format = None;
+
+    for _ifd in 0..ifd_count {
+        let (tag, value) = read_ifd_entry(reader, &endianness, &tiff_type)?;
+
+        match tag {
+            0x100 => width = value,
+            0x101 => height = value,
+            0x102 => bits_per_sample = value,
+            0x115 => samples_per_pixel = value,
+            0x153 => sample_format = value,
+            _ => (),
+        }
+    }
+
+    let (width, height) = match (width, height) {
+        (Some(width), Some(height)) => (width as usize, height as usize),
+        _ => {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "No dimensions in IFD tags",
+            )
+            .into())
+        }
+    };
+
+    let samples_per_pixel = samples_per_pixel.unwrap_or(1);
+    let bits_per_channel = if samples_per_pixel <= 1 {
+        bits_per_sample.unwrap_or(8) as u16
+    } else {
+        8
+    };
+    let is_float = samples_per_pixel <= 1 && sample_format == Some(3);
+
+    let channels = match samples_per_pixel {
+        1 => crate::ChannelLayout::Gray,
+        2 => crate::ChannelLayout::GrayAlpha,
+        3 => crate::ChannelLayout::Rgb,
+        _ => crate::ChannelLayout::Rgba,
+    };
+
+    Ok(crate::ImageInfo {
+        width,
+        height,
+        bits_per_channel,
+        channels,
+        is_float,
+    })
+}
+
 pub fn matches(header: &[u8]) -> bool {
     const TYPE_MARKERS: [u8; 2] = [b'\x2A', b'\x2B'];
     (header.starts_with(b"II") && TYPE_MARKERS.contains(&header[2]) && header[3] == 0)
         || (header.starts_with(b"MM\x00") && TYPE_MARKERS.contains(&header[3]))
 }
+
+/// Reads the dimensions of every page/sub-image in a multi-IFD TIFF, in
+/// directory order, by following each IFD's "next IFD offset" field until it
+/// hits 0.
+pub fn sizes<R: BufRead + Seek>(reader: &mut R) -> ImageResult<Vec<ImageSize>> {
+    sizes_with_limits(reader, &crate::Limits::default())
+}
+
+/// Like [`sizes`], but returns [`crate::ImageError::LimitsExceeded`] instead
+/// of following more than `limits.max_directories` IFDs or scanning an IFD
+/// with more than `limits.max_entries` entries. IFD offsets are also tracked
+/// to detect a directory chain that loops back on itself.
+pub fn sizes_with_limits<R: BufRead + Seek>(
+    reader: &mut R,
+    limits: &crate::Limits,
+) -> ImageResult<Vec<ImageSize>> {
+    reader.seek(SeekFrom::Start(0))?;
+
+    let (endianness, tiff_type, mut ifd_offset) = read_header(reader)?;
+
+    let mut sizes = Vec::new();
+    let mut visited_offsets = std::collections::HashSet::new();
+    let mut directories = 0u64;
+
+    while ifd_offset != 0 {
+        //  A directory chain that revisits an offset is either a malformed
+        //  or deliberately hostile file; stop rather than loop forever.
+        if !visited_offsets.insert(ifd_offset) {
+            break;
+        }
+
+        directories += 1;
+        if directories > limits.max_directories {
+            return Err(crate::ImageError::LimitsExceeded);
+        }
+
+        reader.seek(SeekFrom::Start(ifd_offset))?;
+
+        let ifd_count = if tiff_type == Type::Tiff {
+            read_u16(reader, &endianness)? as u64
+        } else {
+            read_u64(reader, &endianness)?
+        };
+
+        if ifd_count > limits.max_entries {
+            return Err(crate::ImageError::LimitsExceeded);
+        }
+
+        let mut width = None;
+        let mut height = None;
+
+        for _ifd in 0..ifd_count {
+            let (tag, value) = read_ifd_entry(reader, &endianness, &tiff_type)?;
+
+            if tag == 0x100 {
+                width = value;
+            } else if tag == 0x101 {
+                height = value;
+            }
+        }
+
+        if let (Some(width), Some(height)) = (width, height) {
+            sizes.push(ImageSize {
+                width: width as usize,
+                height: height as usize,
+            });
+        }
+
+        //  Right after the IFD entries comes the offset of the next IFD;
+        //  0 terminates the chain.
+        ifd_offset = if tiff_type == Type::Tiff {
+            read_u32(reader, &endianness)? as u64
+        } else {
+            read_u64(reader, &endianness)?
+        };
+    }
+
+    if sizes.is_empty() {
+        return Err(
+            std::io::Error::new(std::io::ErrorKind::InvalidData, "No dimensions in IFD tags")
+                .into(),
+        );
+    }
+
+    Ok(sizes)
+}
@@ -0,0 +1,228 @@
+//! Reader/error abstractions that format modules can depend on instead of
+//! `std::io` directly. This is groundwork for an eventual `no_std` mode: a
+//! format written against [`ImageReader`] and [`SliceCursor`] doesn't need to
+//! change once the rest of the crate (feature gating in particular, which
+//! needs a `Cargo.toml` this tree doesn't have) catches up.
+//!
+//! `astc`, `pkm`, `atc`, and `pvrtc` have already been migrated onto
+//! [`ImageReader`] as a reference point for the rest; every other format
+//! still reads against `std::io::{BufRead, Seek}` directly, which keeps
+//! working unchanged since the blanket impl below gives them `ImageReader`
+//! for free.
+
+/// A minimal, `std`-independent stand-in for the parts of `std::io::Error`
+/// this crate actually inspects.
+pub trait IoError {
+    /// Whether this error represents running out of data mid-read, the way
+    /// `std::io::ErrorKind::UnexpectedEof` does.
+    fn is_unexpected_eof(&self) -> bool;
+}
+
+impl IoError for std::io::Error {
+    fn is_unexpected_eof(&self) -> bool {
+        self.kind() == std::io::ErrorKind::UnexpectedEof
+    }
+}
+
+/// A `no_std`-friendly mirror of `std::io::SeekFrom`, used by
+/// [`ImageReader::seek`] so the trait doesn't have to name `std::io` itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeekFrom {
+    /// Seek to an absolute byte offset from the start of the stream.
+    Start(u64),
+    /// Seek to a byte offset relative to the end of the stream.
+    End(i64),
+    /// Seek to a byte offset relative to the current position.
+    Current(i64),
+}
+
+impl From<SeekFrom> for std::io::SeekFrom {
+    fn from(pos: SeekFrom) -> std::io::SeekFrom {
+        match pos {
+            SeekFrom::Start(offset) => std::io::SeekFrom::Start(offset),
+            SeekFrom::End(offset) => std::io::SeekFrom::End(offset),
+            SeekFrom::Current(offset) => std::io::SeekFrom::Current(offset),
+        }
+    }
+}
+
+/// The error type returned by [`ImageReader`] operations, kept independent
+/// of `std::io::Error` so the trait can be implemented without `std`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadError {
+    /// The read ran out of data before filling the requested buffer, or the
+    /// seek landed outside the stream.
+    UnexpectedEof,
+}
+
+impl IoError for ReadError {
+    fn is_unexpected_eof(&self) -> bool {
+        matches!(self, ReadError::UnexpectedEof)
+    }
+}
+
+/// The reader capability format modules actually need: fill a buffer, and
+/// seek to a position. Implemented for anything that is `std::io::{BufRead,
+/// Seek}` today; a future `no_std` build would keep just this trait and the
+/// [`SliceCursor`] impl below.
+pub trait ImageReader {
+    /// Fills `buf` completely, returning [`ReadError::UnexpectedEof`] if the
+    /// stream runs out first.
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), ReadError>;
+
+    /// Seeks to `pos`, returning the new absolute position from the start of
+    /// the stream.
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64, ReadError>;
+
+    /// Reads up to the next `\n` (not included) into `buf`, stopping early
+    /// if `buf` fills up first, and returns the number of bytes written.
+    ///
+    /// Unlike `std::io::BufRead::read_line`, this writes into a
+    /// caller-provided buffer instead of allocating a `String`, so formats
+    /// with line-oriented headers (PNM, Radiance HDR) can be read without
+    /// `alloc`.
+    fn read_line_capped(&mut self, buf: &mut [u8]) -> Result<usize, ReadError>;
+}
+
+impl<T: std::io::BufRead + std::io::Seek> ImageReader for T {
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), ReadError> {
+        std::io::Read::read_exact(self, buf).map_err(|_| ReadError::UnexpectedEof)
+    }
+
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64, ReadError> {
+        std::io::Seek::seek(self, pos.into()).map_err(|_| ReadError::UnexpectedEof)
+    }
+
+    fn read_line_capped(&mut self, buf: &mut [u8]) -> Result<usize, ReadError> {
+        let mut len = 0;
+
+        while len < buf.len() {
+            let mut byte = [0u8; 1];
+            if std::io::Read::read(self, &mut byte).map_err(|_| ReadError::UnexpectedEof)? == 0 {
+                break;
+            }
+
+            if byte[0] == b'\n' {
+                break;
+            }
+
+            buf[len] = byte[0];
+            len += 1;
+        }
+
+        Ok(len)
+    }
+}
+
+/// A `no_std`-friendly cursor over an in-memory byte slice, supporting just
+/// the read/seek operations the format parsers need.
+///
+/// Unlike `std::io::Cursor`, this doesn't require `std::io::{Read, Seek}` to
+/// be in scope, so it can back a future `blob_size`/`image_type` path that
+/// works purely over `&[u8]` without `std`.
+pub struct SliceCursor<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> SliceCursor<'a> {
+    /// Wraps `data` in a cursor starting at offset 0.
+    pub fn new(data: &'a [u8]) -> SliceCursor<'a> {
+        SliceCursor { data, pos: 0 }
+    }
+
+    /// Current read position.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Moves the cursor to an absolute offset from the start of the slice.
+    pub fn seek_from_start(&mut self, offset: usize) {
+        self.pos = offset;
+    }
+
+    /// Moves the cursor by `offset` bytes relative to its current position.
+    pub fn seek_from_current(&mut self, offset: i64) {
+        self.pos = (self.pos as i64 + offset).max(0) as usize;
+    }
+
+    /// Total number of bytes backing this cursor.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Whether this cursor has no backing data.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Fills `buf` from the cursor, advancing its position.
+    ///
+    /// Returns `Err` with [`IoError::is_unexpected_eof`] true if fewer than
+    /// `buf.len()` bytes remain.
+    pub fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), SliceCursorError> {
+        let end = self.pos.saturating_add(buf.len());
+        if end > self.data.len() {
+            return Err(SliceCursorError::UnexpectedEof);
+        }
+
+        buf.copy_from_slice(&self.data[self.pos..end]);
+        self.pos = end;
+
+        Ok(())
+    }
+}
+
+/// The error type returned by [`SliceCursor`] operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SliceCursorError {
+    /// The cursor ran out of data before satisfying the read.
+    UnexpectedEof,
+}
+
+impl IoError for SliceCursorError {
+    fn is_unexpected_eof(&self) -> bool {
+        matches!(self, SliceCursorError::UnexpectedEof)
+    }
+}
+
+impl ImageReader for SliceCursor<'_> {
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), ReadError> {
+        SliceCursor::read_exact(self, buf).map_err(|_| ReadError::UnexpectedEof)
+    }
+
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64, ReadError> {
+        let new_pos = match pos {
+            SeekFrom::Start(offset) => offset as i64,
+            SeekFrom::Current(offset) => self.pos as i64 + offset,
+            SeekFrom::End(offset) => self.data.len() as i64 + offset,
+        };
+
+        if new_pos < 0 || new_pos as usize > self.data.len() {
+            return Err(ReadError::UnexpectedEof);
+        }
+
+        self.pos = new_pos as usize;
+        Ok(self.pos as u64)
+    }
+
+    fn read_line_capped(&mut self, buf: &mut [u8]) -> Result<usize, ReadError> {
+        let mut len = 0;
+
+        while len < buf.len() {
+            let mut byte = [0u8; 1];
+            if self.read_exact(&mut byte).is_err() {
+                break;
+            }
+
+            if byte[0] == b'\n' {
+                break;
+            }
+
+            buf[len] = byte[0];
+            len += 1;
+        }
+
+        Ok(len)
+    }
+}
@@ -3,17 +3,25 @@
 use std::error::Error;
 use std::fmt;
 use std::fs::File;
-use std::io::{BufRead, BufReader, Cursor, Seek};
+use std::io::{BufRead, BufReader, Cursor, Seek, SeekFrom};
 use std::path::Path;
 
 mod container;
 mod formats;
+mod io;
 mod util;
 
 pub use container::{
-    atc::AtcCompression, dds::DdsCompression, heif::Compression, pkm::PkmCompression,
+    atc::AtcCompression,
+    dds::{DdsCompression, DdsMetadata},
+    heif::Compression,
+    pkm::PkmCompression,
     pvrtc::PvrtcCompression,
 };
+#[cfg(feature = "astc")]
+pub use formats::astc::AstcCompression;
+#[cfg(feature = "ktx2")]
+pub use formats::ktx2::Ktx2Compression;
 
 /// Groups related compression algorithms regardless of their container format
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -48,6 +56,10 @@ pub enum ImageError {
     CorruptedImage,
     /// Used when an IoError occurs when trying to read the given data.
     IoError(std::io::Error),
+    /// Used when parsing a directory/box-walking format (TIFF, HEIF, ...)
+    /// exceeds the configured [`Limits`], most likely because the file is
+    /// malformed or deliberately hostile.
+    LimitsExceeded,
 }
 
 impl Error for ImageError {}
@@ -59,6 +71,7 @@ impl fmt::Display for ImageError {
             NotSupported => f.write_str("Could not decode image"),
             CorruptedImage => f.write_str("Hit end of file before finding size"),
             IoError(error) => error.fmt(f),
+            LimitsExceeded => f.write_str("Exceeded configured limits while parsing image"),
         }
     }
 }
@@ -69,6 +82,16 @@ impl From<std::io::Error> for ImageError {
     }
 }
 
+impl From<io::ReadError> for ImageError {
+    fn from(err: io::ReadError) -> ImageError {
+        match err {
+            io::ReadError::UnexpectedEof => {
+                ImageError::IoError(std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "Unexpected EOF"))
+            }
+        }
+    }
+}
+
 pub type ImageResult<T> = Result<T, ImageError>;
 
 /// Types of image formats that this crate can identify.
@@ -147,9 +170,10 @@ pub enum ImageType {
     /// <https://github.com/aseprite/aseprite>
     #[cfg(feature = "aesprite")]
     Aseprite,
-    /// Adaptive Scalable Texture Compression
+    /// Adaptive Scalable Texture Compression, carrying the block footprint
+    /// read from the file header
     #[cfg(feature = "astc")]
-    Astc,
+    Astc(AstcCompression),
     /// Adaptive Texture Compression
     #[cfg(feature = "atc")]
     Atc(AtcCompression),
@@ -193,9 +217,13 @@ pub enum ImageType {
     /// JPEG XL
     #[cfg(feature = "jxl")]
     Jxl,
-    /// Khronos Texture Container
+    /// Khronos Texture Container, carrying the compression decoded from its
+    /// `vkFormat` field
     #[cfg(feature = "ktx2")]
-    Ktx2,
+    Ktx2(Ktx2Compression),
+    /// QuickDraw PICT/PICT2
+    #[cfg(feature = "pict")]
+    Pict,
     /// Standard PNG
     #[cfg(feature = "png")]
     Png,
@@ -290,8 +318,19 @@ impl ImageType {
             ImageType::Atc(_) => Some(CompressionFamily::Atc),
             
             #[cfg(feature = "astc")]
-            ImageType::Astc => Some(CompressionFamily::Astc),
-            
+            ImageType::Astc(..) => Some(CompressionFamily::Astc),
+
+            #[cfg(feature = "ktx2")]
+            ImageType::Ktx2(compression) => match compression {
+                Ktx2Compression::Bc1 | Ktx2Compression::Bc2 | Ktx2Compression::Bc3 |
+                Ktx2Compression::Bc4 | Ktx2Compression::Bc5 | Ktx2Compression::Bc6h |
+                Ktx2Compression::Bc7 => Some(CompressionFamily::BlockCompression),
+                Ktx2Compression::Etc2 => Some(CompressionFamily::Etc),
+                Ktx2Compression::Eac => Some(CompressionFamily::Eac),
+                Ktx2Compression::Astc => Some(CompressionFamily::Astc),
+                Ktx2Compression::Unknown => None,
+            },
+
             // Simple formats don't have compression families
             _ => None,
         }
@@ -316,6 +355,194 @@ impl ImageType {
         matches!(self.compression_family(), Some(CompressionFamily::BlockCompression))
     }
 
+    /// Returns the number of bytes a single mip level of `width` x `height`
+    /// occupies for GPU texture formats, or `None` for uncompressed, unknown,
+    /// or non-texture formats.
+    ///
+    /// Useful for memory budgeting and for validating that a file isn't
+    /// truncated before its texture data is uploaded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use imagesize::{ImageType, DdsCompression, PvrtcCompression};
+    ///
+    /// let bc1_type = ImageType::Dds(DdsCompression::Bc1);
+    /// assert_eq!(bc1_type.encoded_data_size(16, 16), Some(8 * 4 * 4));
+    ///
+    /// let png_type = ImageType::Png;
+    /// assert_eq!(png_type.encoded_data_size(16, 16), None);
+    /// ```
+    pub fn encoded_data_size(&self, width: usize, height: usize) -> Option<usize> {
+        #[cfg(feature = "astc")]
+        if let ImageType::Astc(compression) = self {
+            let (block_x, block_y) = compression.block_dimensions()?;
+            let blocks_x = (width + block_x as usize - 1) / block_x as usize;
+            let blocks_y = (height + block_y as usize - 1) / block_y as usize;
+            return Some(blocks_x * blocks_y * 16);
+        }
+
+        #[cfg(feature = "pvrtc")]
+        if let ImageType::Pvrtc(compression) = self {
+            return match compression {
+                PvrtcCompression::Pvrtc4BppRgb | PvrtcCompression::Pvrtc4BppRgba => {
+                    Some(width.max(8) * height.max(8) * 4 / 8)
+                }
+                PvrtcCompression::Pvrtc2BppRgb | PvrtcCompression::Pvrtc2BppRgba => {
+                    Some(width.max(16) * height.max(8) * 2 / 8)
+                }
+                // ETC2/EAC variants stored in a PowerVR container use the
+                // same 4x4 block byte counts as their DDS/PKM counterparts.
+                PvrtcCompression::Etc2Rgb | PvrtcCompression::Etc2RgbA1 => {
+                    Some(((width + 3) / 4) * ((height + 3) / 4) * 8)
+                }
+                PvrtcCompression::Etc2Rgba | PvrtcCompression::EacRg11 => {
+                    Some(((width + 3) / 4) * ((height + 3) / 4) * 16)
+                }
+                PvrtcCompression::EacR11 => Some(((width + 3) / 4) * ((height + 3) / 4) * 8),
+                PvrtcCompression::Unknown => None,
+            };
+        }
+
+        #[cfg(feature = "dds")]
+        if let ImageType::Dds(compression) = self {
+            let block_bytes = match compression {
+                DdsCompression::Bc1 | DdsCompression::Bc4 => 8,
+                DdsCompression::Bc2
+                | DdsCompression::Bc3
+                | DdsCompression::Bc5
+                | DdsCompression::Bc6h
+                | DdsCompression::Bc7 => 16,
+                DdsCompression::Rgba32 | DdsCompression::Rgb24 | DdsCompression::Unknown => {
+                    return None
+                }
+            };
+            let blocks_x = (width + 3) / 4;
+            let blocks_y = (height + 3) / 4;
+            return Some(blocks_x * blocks_y * block_bytes);
+        }
+
+        #[cfg(feature = "etc2")]
+        if let ImageType::Etc2(compression) = self {
+            let block_bytes = match compression {
+                PkmCompression::Etc1 | PkmCompression::Etc2 | PkmCompression::Etc2A1 => 8,
+                PkmCompression::Etc2A8 => 16,
+                _ => return None,
+            };
+            let blocks_x = (width + 3) / 4;
+            let blocks_y = (height + 3) / 4;
+            return Some(blocks_x * blocks_y * block_bytes);
+        }
+
+        #[cfg(feature = "eac")]
+        if let ImageType::Eac(compression) = self {
+            let block_bytes = match compression {
+                // Single-channel R11 is one 8-byte block, same as ETC1/ETC2-RGB.
+                PkmCompression::EacR | PkmCompression::EacRSigned => 8,
+                // Dual-channel RG11 is two 8-byte blocks.
+                PkmCompression::EacRg | PkmCompression::EacRgSigned => 16,
+                _ => return None,
+            };
+            let blocks_x = (width + 3) / 4;
+            let blocks_y = (height + 3) / 4;
+            return Some(blocks_x * blocks_y * block_bytes);
+        }
+
+        None
+    }
+
+    /// Returns the number of bytes the encoded pixel payload occupies across
+    /// `mipmaps` mip levels (each halved in width/height, floored at `1`,
+    /// relative to the previous level), or `None` for formats whose payload
+    /// size isn't derivable from dimensions alone (JPEG, PNG, etc.).
+    ///
+    /// This extends [`encoded_data_size`](Self::encoded_data_size) to also
+    /// cover uncompressed DDS formats and to sum an entire mip chain instead
+    /// of a single level.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use imagesize::{ImageType, DdsCompression};
+    ///
+    /// let bc1_type = ImageType::Dds(DdsCompression::Bc1);
+    /// assert_eq!(bc1_type.data_size(16, 16, 1), Some(4 * 4 * 8));
+    /// assert_eq!(bc1_type.data_size(16, 16, 2), Some(4 * 4 * 8 + 2 * 2 * 8));
+    /// ```
+    pub fn data_size(&self, width: usize, height: usize, mipmaps: usize) -> Option<usize> {
+        let mut total = 0usize;
+        let (mut w, mut h) = (width, height);
+
+        for _ in 0..mipmaps.max(1) {
+            total += self.per_level_data_size(w, h)?;
+            w = (w / 2).max(1);
+            h = (h / 2).max(1);
+        }
+
+        Some(total)
+    }
+
+    fn per_level_data_size(&self, width: usize, height: usize) -> Option<usize> {
+        #[cfg(feature = "dds")]
+        if let ImageType::Dds(compression) = self {
+            match compression {
+                DdsCompression::Rgba32 => return Some(width * height * 4),
+                DdsCompression::Rgb24 => return Some(width * height * 3),
+                _ => {}
+            }
+        }
+
+        self.encoded_data_size(width, height)
+    }
+
+    /// Returns true if the format carries high-dynamic-range (float or
+    /// half-float) sample data rather than standard 8-bit-per-channel color.
+    ///
+    /// This covers OpenEXR (always half/float channels), Radiance HDR
+    /// (always packed RGBE), and the BC6H block-compression mode in both DDS
+    /// and KTX2 (the only HDR-capable block format in either container,
+    /// distinct from the LDR BC7 format already covered by
+    /// [`is_block_compressed`](Self::is_block_compressed)).
+    ///
+    /// DDS can also carry uncompressed float formats (e.g. `R16_FLOAT`,
+    /// `R32G32B32A32_FLOAT`) via its DXGI pixel format, but `DdsCompression`
+    /// has no variant for those today, so a DDS file using one of them
+    /// always reports `false` here rather than `true`.
+    ///
+    /// JPEG XL can also declare HDR (>8-bit or float) samples, but that bit
+    /// depth lives in the per-file bitstream rather than anywhere
+    /// `ImageType` currently captures, so JXL files always report `false`
+    /// here.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use imagesize::{ImageType, DdsCompression};
+    ///
+    /// let bc6h_type = ImageType::Dds(DdsCompression::Bc6h);
+    /// assert!(bc6h_type.is_hdr());
+    ///
+    /// let bc7_type = ImageType::Dds(DdsCompression::Bc7);
+    /// assert!(!bc7_type.is_hdr());
+    /// ```
+    pub fn is_hdr(&self) -> bool {
+        match self {
+            #[cfg(feature = "exr")]
+            ImageType::Exr => true,
+
+            #[cfg(feature = "hdr")]
+            ImageType::Hdr => true,
+
+            #[cfg(feature = "dds")]
+            ImageType::Dds(DdsCompression::Bc6h) => true,
+
+            #[cfg(feature = "ktx2")]
+            ImageType::Ktx2(Ktx2Compression::Bc6h) => true,
+
+            _ => false,
+        }
+    }
+
     /// Returns the container format name for texture formats
     ///
     /// Returns a human-readable string identifying the container format.
@@ -353,13 +580,13 @@ impl ImageType {
             ImageType::Atc(_) => Some("PKM"),  // ATC typically uses PKM containers
             
             #[cfg(feature = "astc")]
-            ImageType::Astc => Some("ASTC"),   // Direct ASTC format
+            ImageType::Astc(..) => Some("ASTC"),   // Direct ASTC format
             
             #[cfg(feature = "heif")]
             ImageType::Heif(_) => Some("HEIF"),
             
             #[cfg(feature = "ktx2")]
-            ImageType::Ktx2 => Some("KTX2"),
+            ImageType::Ktx2(..) => Some("KTX2"),
             
             // Simple formats don't have containers
             _ => None,
@@ -393,7 +620,7 @@ impl ImageType {
             ImageType::Pvrtc(_) => true,      // PowerVR supports PVRTC, ETC2, EAC
             
             #[cfg(feature = "ktx2")]
-            ImageType::Ktx2 => true,          // KTX2 supports many formats
+            ImageType::Ktx2(..) => true,      // KTX2 supports many formats
             
             _ => false,
         }
@@ -408,7 +635,7 @@ impl ImageType {
             #[cfg(feature = "aesprite")]
             ImageType::Aseprite => aesprite::size(reader),
             #[cfg(feature = "astc")]
-            ImageType::Astc => astc::size(reader),
+            ImageType::Astc(..) => astc::size(reader),
             #[cfg(feature = "atc")]
             ImageType::Atc(..) => container::atc::size(reader),
             #[cfg(feature = "bmp")]
@@ -436,7 +663,9 @@ impl ImageType {
             #[cfg(feature = "jxl")]
             ImageType::Jxl => jxl::size(reader),
             #[cfg(feature = "ktx2")]
-            ImageType::Ktx2 => ktx2::size(reader),
+            ImageType::Ktx2(..) => ktx2::size(reader),
+            #[cfg(feature = "pict")]
+            ImageType::Pict => pict::size(reader),
             #[cfg(feature = "png")]
             ImageType::Png => png::size(reader),
             #[cfg(feature = "pnm")]
@@ -460,6 +689,501 @@ impl ImageType {
             ImageType::Heif(..) => heif::size(reader),
         }
     }
+
+    /// Like [`reader_size`](ImageType::reader_size), but also reports the
+    /// [`Orientation`] the stored dimensions should be displayed with.
+    ///
+    /// Formats that don't carry orientation metadata (or don't support it
+    /// yet) report [`Orientation::Normal`] alongside their regular size.
+    pub fn reader_size_with_orientation<R: BufRead + Seek>(
+        &self,
+        reader: &mut R,
+    ) -> ImageResult<(ImageSize, Orientation)> {
+        match self {
+            #[cfg(feature = "tiff")]
+            ImageType::Tiff => tiff::size_with_orientation(reader),
+            #[cfg(feature = "heif")]
+            ImageType::Heif(..) => heif::size_with_orientation(reader),
+            #[cfg(feature = "jpeg")]
+            ImageType::Jpeg => jpeg::size_raw(reader),
+            #[cfg(feature = "jxl")]
+            ImageType::Jxl => jxl::size_raw(reader),
+            #[cfg(feature = "tga")]
+            ImageType::Tga => tga::size_with_orientation(reader),
+            _ => Ok((self.reader_size(reader)?, Orientation::Normal)),
+        }
+    }
+
+    /// Like [`reader_size`](ImageType::reader_size), but bounds the work
+    /// done while walking directory/box-based formats to the given
+    /// [`Limits`], returning [`ImageError::LimitsExceeded`] instead of
+    /// chasing an attacker-controlled count or offset chain.
+    ///
+    /// Formats that don't walk a variable-length directory structure ignore
+    /// `limits` entirely.
+    pub fn reader_size_with_limits<R: BufRead + Seek>(
+        &self,
+        reader: &mut R,
+        limits: &Limits,
+    ) -> ImageResult<ImageSize> {
+        match self {
+            #[cfg(feature = "tiff")]
+            ImageType::Tiff => tiff::size_with_limits(reader, limits),
+            #[cfg(feature = "heif")]
+            ImageType::Heif(..) => heif::size_with_limits(reader, limits),
+            _ => self.reader_size(reader),
+        }
+    }
+
+    /// Returns the dimensions of every page/sub-image in a multi-image
+    /// container, in order: every IFD of a TIFF, every mip level of a DDS
+    /// /PVRTC/KTX2 texture, or every frame's Image Descriptor of a GIF.
+    ///
+    /// Formats that don't support multiple pages/sub-images yet return a
+    /// single-element `Vec` with the same result as
+    /// [`reader_size`](ImageType::reader_size). This includes APNG, since
+    /// this crate doesn't parse PNG chunks at all.
+    pub fn reader_sizes<R: BufRead + Seek>(&self, reader: &mut R) -> ImageResult<Vec<ImageSize>> {
+        match self {
+            #[cfg(feature = "gif")]
+            ImageType::Gif => gif::sizes(reader),
+            #[cfg(feature = "tiff")]
+            ImageType::Tiff => tiff::sizes(reader),
+            #[cfg(feature = "dds")]
+            ImageType::Dds(..) => {
+                let meta = self.reader_container_metadata(reader)?;
+                Ok(mip_chain(meta.width, meta.height, meta.mip_levels))
+            }
+            #[cfg(feature = "pvrtc")]
+            ImageType::Pvrtc(..) => {
+                let meta = self.reader_container_metadata(reader)?;
+                Ok(mip_chain(meta.width, meta.height, meta.mip_levels))
+            }
+            #[cfg(feature = "ktx2")]
+            ImageType::Ktx2(..) => {
+                let meta = self.reader_container_metadata(reader)?;
+                Ok(mip_chain(meta.width, meta.height, meta.mip_levels))
+            }
+            _ => Ok(vec![self.reader_size(reader)?]),
+        }
+    }
+
+    /// Returns the number of frames/pages/items/array layers held by a
+    /// multi-image or texture array container, or `None` for formats that
+    /// only ever hold a single image.
+    pub fn reader_frame_count<R: BufRead + Seek>(
+        &self,
+        reader: &mut R,
+    ) -> ImageResult<Option<usize>> {
+        match self {
+            #[cfg(feature = "gif")]
+            ImageType::Gif => Ok(Some(gif::frame_count(reader)?)),
+            #[cfg(feature = "tiff")]
+            ImageType::Tiff => Ok(Some(tiff::sizes(reader)?.len())),
+            #[cfg(feature = "heif")]
+            ImageType::Heif(..) => Ok(Some(heif::item_count(reader)?)),
+            #[cfg(feature = "dds")]
+            ImageType::Dds(..) => Ok(Some(self.reader_container_metadata(reader)?.array_layers)),
+            #[cfg(feature = "pvrtc")]
+            ImageType::Pvrtc(..) => Ok(Some(self.reader_container_metadata(reader)?.array_layers)),
+            #[cfg(feature = "ktx2")]
+            ImageType::Ktx2(..) => Ok(Some(self.reader_container_metadata(reader)?.array_layers)),
+            _ => Ok(None),
+        }
+    }
+
+    /// Returns the full volume/array shape of a texture container: width,
+    /// height, depth, mipmap level count, array layer count, and face count.
+    ///
+    /// Formats without this kind of structure (or without support for it
+    /// yet) fall back to [`reader_size`](ImageType::reader_size) with `1` for
+    /// every count that doesn't apply to a plain 2D image.
+    pub fn reader_container_metadata<R: BufRead + Seek>(
+        &self,
+        reader: &mut R,
+    ) -> ImageResult<ContainerMetadata> {
+        match self {
+            #[cfg(feature = "pvrtc")]
+            ImageType::Pvrtc(..) => container::pvrtc::container_metadata(reader),
+            #[cfg(feature = "ktx2")]
+            ImageType::Ktx2(..) => ktx2::container_metadata(reader),
+            #[cfg(feature = "dds")]
+            ImageType::Dds(..) => container::dds::container_metadata(reader),
+            #[cfg(feature = "astc")]
+            ImageType::Astc(..) => {
+                let (size, depth) = astc::size_3d(reader)?;
+                Ok(ContainerMetadata {
+                    width: size.width,
+                    height: size.height,
+                    depth,
+                    mip_levels: 1,
+                    array_layers: 1,
+                    faces: 1,
+                    image_type: *self,
+                })
+            }
+            _ => {
+                let size = self.reader_size(reader)?;
+                Ok(ContainerMetadata {
+                    width: size.width,
+                    height: size.height,
+                    depth: 1,
+                    mip_levels: 1,
+                    array_layers: 1,
+                    faces: 1,
+                    image_type: *self,
+                })
+            }
+        }
+    }
+
+    /// Returns the mipmap/array/face/depth shape of a texture container.
+    ///
+    /// Unlike [`reader_container_metadata`](Self::reader_container_metadata),
+    /// this returns [`ImageError::NotSupported`] for formats that aren't a
+    /// texture container instead of defaulting every count to `1`.
+    pub fn reader_texture_info<R: BufRead + Seek>(&self, reader: &mut R) -> ImageResult<TextureInfo> {
+        let is_texture_container = match self {
+            #[cfg(feature = "dds")]
+            ImageType::Dds(..) => true,
+            #[cfg(feature = "ktx2")]
+            ImageType::Ktx2(..) => true,
+            #[cfg(feature = "pvrtc")]
+            ImageType::Pvrtc(..) => true,
+            _ => false,
+        };
+
+        if !is_texture_container {
+            return Err(ImageError::NotSupported);
+        }
+
+        let meta = self.reader_container_metadata(reader)?;
+        Ok(TextureInfo {
+            size: ImageSize {
+                width: meta.width,
+                height: meta.height,
+            },
+            depth: meta.depth,
+            mipmap_count: meta.mip_levels,
+            array_layers: meta.array_layers,
+            faces: meta.faces,
+        })
+    }
+
+    /// Returns the pixel format (bit depth, channel layout, float-vs-integer
+    /// samples) alongside dimensions, for the formats that carry this
+    /// information directly in their header.
+    ///
+    /// Returns [`ImageError::NotSupported`] for formats that aren't wired up
+    /// to this yet, rather than guessing at values the header doesn't
+    /// actually contain.
+    pub fn reader_image_info<R: BufRead + Seek>(&self, reader: &mut R) -> ImageResult<ImageInfo> {
+        match self {
+            #[cfg(feature = "tga")]
+            ImageType::Tga => tga::image_info(reader),
+            #[cfg(feature = "tiff")]
+            ImageType::Tiff => tiff::image_info(reader),
+            #[cfg(feature = "exr")]
+            ImageType::Exr => exr::image_info(reader),
+            _ => Err(ImageError::NotSupported),
+        }
+    }
+
+    /// Returns pixel/block format information: bit depth and channel layout
+    /// for formats [`reader_image_info`](ImageType::reader_image_info)
+    /// already supports, or the texel block footprint for block-compressed
+    /// formats with a known one.
+    ///
+    /// Returns [`ImageError::NotSupported`] for formats with neither.
+    pub fn reader_meta<R: BufRead + Seek>(&self, reader: &mut R) -> ImageResult<ImageMeta> {
+        if let Ok(info) = self.reader_image_info(reader) {
+            return Ok(ImageMeta {
+                width: info.width,
+                height: info.height,
+                bits_per_channel: Some(info.bits_per_channel),
+                channels: Some(info.channels),
+                is_float: Some(info.is_float),
+                block_dimensions: None,
+            });
+        }
+
+        let block_dimensions = match self {
+            #[cfg(feature = "astc")]
+            ImageType::Astc(compression) => compression.block_dimensions(),
+            #[cfg(feature = "dds")]
+            ImageType::Dds(compression) => match compression {
+                DdsCompression::Rgba32 | DdsCompression::Rgb24 | DdsCompression::Unknown => None,
+                _ => Some((4, 4)),
+            },
+            #[cfg(feature = "etc2")]
+            ImageType::Etc2(_) => Some((4, 4)),
+            #[cfg(feature = "eac")]
+            ImageType::Eac(_) => Some((4, 4)),
+            _ => None,
+        };
+
+        let Some(block_dimensions) = block_dimensions else {
+            return Err(ImageError::NotSupported);
+        };
+
+        let size = self.reader_size(reader)?;
+        Ok(ImageMeta {
+            width: size.width,
+            height: size.height,
+            bits_per_channel: None,
+            channels: None,
+            is_float: None,
+            block_dimensions: Some(block_dimensions),
+        })
+    }
+}
+
+/// Describes the rotation/flip that must be applied to the stored pixel data
+/// to obtain the image as it should be displayed.
+///
+/// Variant names follow the transform they represent rather than a specific
+/// format's tag values; use [`Orientation::from_exif`] or
+/// [`Orientation::from_irot`] to build one from a format-specific field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// No transform needed.
+    Normal,
+    /// Flip across the vertical axis.
+    FlipHorizontal,
+    /// Rotate 180 degrees.
+    Rotate180,
+    /// Flip across the horizontal axis.
+    FlipVertical,
+    /// Flip across the top-left/bottom-right diagonal.
+    Transpose,
+    /// Rotate 90 degrees clockwise.
+    Rotate90,
+    /// Flip across the top-right/bottom-left diagonal.
+    Transverse,
+    /// Rotate 270 degrees clockwise.
+    Rotate270,
+}
+
+impl Orientation {
+    /// Builds an `Orientation` from an EXIF `Orientation` tag (0x0112) value.
+    ///
+    /// Values outside of the defined 1-8 range (including 0, which some
+    /// writers use to mean "unknown") are treated as `Normal`.
+    pub fn from_exif(value: u16) -> Orientation {
+        match value {
+            2 => Orientation::FlipHorizontal,
+            3 => Orientation::Rotate180,
+            4 => Orientation::FlipVertical,
+            5 => Orientation::Transpose,
+            6 => Orientation::Rotate90,
+            7 => Orientation::Transverse,
+            8 => Orientation::Rotate270,
+            _ => Orientation::Normal,
+        }
+    }
+
+    /// Builds an `Orientation` from a HEIF/ISOBMFF `irot` box value, which
+    /// stores the number of quarter turns (counter-clockwise) applied to the
+    /// stored image to obtain the displayed image.
+    pub fn from_irot(quarter_turns: u8) -> Orientation {
+        match quarter_turns & 0x3 {
+            1 => Orientation::Rotate270,
+            2 => Orientation::Rotate180,
+            3 => Orientation::Rotate90,
+            _ => Orientation::Normal,
+        }
+    }
+
+    /// Returns true if this orientation swaps width and height when applied.
+    pub fn swaps_dimensions(&self) -> bool {
+        matches!(
+            self,
+            Orientation::Transpose
+                | Orientation::Rotate90
+                | Orientation::Transverse
+                | Orientation::Rotate270
+        )
+    }
+
+    /// Applies this orientation to a stored `ImageSize`, returning the
+    /// display-space dimensions (width/height swapped when
+    /// [`swaps_dimensions`](Orientation::swaps_dimensions) is true).
+    pub fn apply(&self, size: ImageSize) -> ImageSize {
+        if self.swaps_dimensions() {
+            ImageSize {
+                width: size.height,
+                height: size.width,
+            }
+        } else {
+            size
+        }
+    }
+}
+
+/// Resource limits applied while walking directory/box-based formats (TIFF,
+/// HEIF/ISOBMFF, ...) so that a hostile file with attacker-controlled counts
+/// or offsets can't force unbounded work.
+///
+/// Borrowed from the same idea as the `tiff` crate's `Limits` type. The
+/// defaults are generous enough for any real-world file; pass a tighter
+/// [`Limits`] to `*_with_limits` entry points when parsing untrusted input
+/// under a stricter budget.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Limits {
+    /// Maximum number of entries scanned in a single directory (e.g. a TIFF
+    /// IFD or a HEIF box's children).
+    pub max_entries: u64,
+    /// Maximum number of directories/boxes followed while looking for one
+    /// (e.g. chasing `next IFD offset` links, or nested ISOBMFF boxes).
+    pub max_directories: u64,
+    /// Maximum number of seek operations performed while parsing. No parser
+    /// in this crate checks this field; seeks are not counted.
+    pub max_seeks: u64,
+}
+
+impl Limits {
+    /// No limits at all. Only use this for trusted input, since it disables
+    /// the protections this type exists to provide.
+    pub fn no_limits() -> Limits {
+        Limits {
+            max_entries: u64::MAX,
+            max_directories: u64::MAX,
+            max_seeks: u64::MAX,
+        }
+    }
+}
+
+impl Default for Limits {
+    fn default() -> Limits {
+        Limits {
+            max_entries: 4096,
+            max_directories: 64,
+            max_seeks: 8192,
+        }
+    }
+}
+
+/// Width/height plus the volume/array shape of a texture container.
+///
+/// Texture containers (PVR, KTX2, DDS, ...) can describe far more than a
+/// single 2D image: mipmap chains, depth slices for volume textures, array
+/// layers, and cubemap faces. Simple image formats report the defaults of
+/// `1` for all of these.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ContainerMetadata {
+    /// Width of the base level, in pixels.
+    pub width: usize,
+    /// Height of the base level, in pixels.
+    pub height: usize,
+    /// Depth of the base level for volume textures. `1` for 2D images.
+    pub depth: usize,
+    /// Number of mipmap levels, including the base level. `1` if there are
+    /// no additional mip levels.
+    pub mip_levels: usize,
+    /// Number of array layers. `1` for a non-array texture.
+    pub array_layers: usize,
+    /// Number of faces; `6` for a complete cubemap, the number of faces
+    /// present for a partial DDS cubemap, `1` otherwise.
+    pub faces: usize,
+    /// The detected image/compression type.
+    pub image_type: ImageType,
+}
+
+/// An [`ImageSize`] paired with the [`ImageType`] that produced it.
+///
+/// For compressed GPU texture formats, `image_type` already carries the
+/// per-format descriptor (e.g. [`ImageType::Etc2`]/[`ImageType::Eac`] hold a
+/// [`PkmCompression`], [`ImageType::Atc`] holds an [`AtcCompression`],
+/// [`ImageType::Pvrtc`] holds a [`PvrtcCompression`]) - this struct exists so
+/// callers can get both the dimensions and that descriptor from a single
+/// read instead of dispatching on the file twice.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImageMetadata {
+    /// The image's dimensions.
+    pub size: ImageSize,
+    /// The detected image/compression type.
+    pub image_type: ImageType,
+}
+
+/// Width/height plus the volume/array shape of a texture container, for
+/// callers that only care about the texture-specific counts (not the
+/// detected [`ImageType`] that [`ContainerMetadata`] also carries).
+///
+/// Returns [`ImageError::NotSupported`] for formats that aren't a texture
+/// container, rather than defaulting every count to `1` the way
+/// [`ContainerMetadata`] does.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TextureInfo {
+    /// Width/height of the base level.
+    pub size: ImageSize,
+    /// Depth of the base level for volume textures. `1` for 2D images.
+    pub depth: usize,
+    /// Number of mipmap levels, including the base level.
+    pub mipmap_count: usize,
+    /// Number of array layers. `1` for a non-array texture.
+    pub array_layers: usize,
+    /// Number of faces; `6` for a complete cubemap, the number of faces
+    /// present for a partial DDS cubemap, `1` otherwise.
+    pub faces: usize,
+}
+
+/// The channel/color-type layout of an image's pixel data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ChannelLayout {
+    /// A single grayscale channel.
+    Gray,
+    /// Grayscale plus an alpha channel.
+    GrayAlpha,
+    /// Red, green, and blue channels.
+    Rgb,
+    /// Red, green, blue, and alpha channels.
+    Rgba,
+    /// Palette-indexed color.
+    Indexed,
+}
+
+/// Width/height plus the pixel format: bit depth, channel layout, and
+/// whether samples are floating point.
+///
+/// This is additive to [`ImageSize`]: formats that don't expose this
+/// information yet simply aren't wired up to [`image_info`] rather than
+/// reporting guessed values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImageInfo {
+    /// Width of the image in pixels.
+    pub width: usize,
+    /// Height of the image in pixels.
+    pub height: usize,
+    /// Bits per channel/sample (e.g. 8 for typical RGB, 16 for HALF-float EXR).
+    pub bits_per_channel: u16,
+    /// The channel layout (grayscale, RGB, RGBA, indexed, ...).
+    pub channels: ChannelLayout,
+    /// Whether samples are stored as floating point rather than integers.
+    pub is_float: bool,
+}
+
+/// Pixel/block format information beyond width and height, covering both
+/// conventional raster formats and compressed GPU texture formats.
+///
+/// This is distinct from [`ImageInfo`]: `ImageInfo` only models formats with
+/// a per-channel bit depth, while block-compressed formats (ASTC, DDS, ETC2,
+/// EAC) instead have a texel block footprint, reported here via
+/// `block_dimensions` with the other fields left `None` rather than guessed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImageMeta {
+    /// Width of the image in pixels.
+    pub width: usize,
+    /// Height of the image in pixels.
+    pub height: usize,
+    /// Bits per channel/sample, for formats with a conventional bit depth.
+    pub bits_per_channel: Option<u16>,
+    /// The channel layout, for formats with a conventional bit depth.
+    pub channels: Option<ChannelLayout>,
+    /// Whether samples are floating point, for formats with a conventional
+    /// bit depth.
+    pub is_float: Option<bool>,
+    /// The `(x, y)` texel block footprint, for block-compressed formats.
+    pub block_dimensions: Option<(u8, u8)>,
 }
 
 /// Holds the size information of an image.
@@ -622,3 +1346,523 @@ pub fn reader_size<R: BufRead + Seek>(mut reader: R) -> ImageResult<ImageSize> {
 pub fn reader_type<R: BufRead + Seek>(mut reader: R) -> ImageResult<ImageType> {
     formats::image_type(&mut reader)
 }
+
+/// Get the image type from a reader, seeking to a given byte offset first.
+///
+/// # Arguments
+/// * `reader` - A reader for the data
+/// * `offset` - The byte offset within `reader` where the embedded image starts
+///
+/// # Remarks
+///
+/// Useful for images packed inside a larger container, such as an EXIF
+/// thumbnail or a texture atlas sub-image, where the caller already knows
+/// where the embedded data begins.
+pub fn reader_type_at<R: BufRead + Seek>(mut reader: R, offset: u64) -> ImageResult<ImageType> {
+    let mut offset_reader = OffsetReader::new(&mut reader, offset);
+    offset_reader.seek(SeekFrom::Start(0))?;
+    reader_type(&mut offset_reader)
+}
+
+/// Get the image size from a reader, seeking to a given byte offset first.
+///
+/// # Arguments
+/// * `reader` - A reader for the data
+/// * `offset` - The byte offset within `reader` where the embedded image starts
+///
+/// # Remarks
+///
+/// Like [`reader_type_at`], this can't just seek once and hand the reader
+/// off: every per-format `size()` re-seeks to its own header fields using
+/// absolute offsets from what it assumes is the start of the file (e.g.
+/// `bmp::size` seeks to `Start(14)`). Read directly, that would read byte 14
+/// of the *container* instead of byte 14 of the embedded image. Wrapping the
+/// reader in [`OffsetReader`] makes `SeekFrom::Start` relative to `offset`
+/// transparently, so every format's size reader works unmodified.
+pub fn reader_size_at<R: BufRead + Seek>(mut reader: R, offset: u64) -> ImageResult<ImageSize> {
+    let mut offset_reader = OffsetReader::new(&mut reader, offset);
+    offset_reader.seek(SeekFrom::Start(0))?;
+    reader_type(&mut offset_reader)?.reader_size(&mut offset_reader)
+}
+
+/// Wraps a reader so that `SeekFrom::Start` is relative to a fixed `base`
+/// offset instead of the underlying stream's true start, letting an
+/// embedded image's per-format size reader use the same absolute-offset
+/// seeks it would use when reading a standalone file.
+///
+/// `SeekFrom::Current` passes straight through, since it's already relative
+/// to wherever the reader is positioned. `SeekFrom::End` also passes
+/// through unchanged: it isn't adjusted for `base` because there's no way
+/// to know where the embedded image's own data ends within the surrounding
+/// container. The one reader that seeks relative to the end, `util::read_tag`
+/// sizing an ISOBMFF box that runs to the end of the stream, only uses the
+/// difference between two returned positions, so `base` cancels out.
+struct OffsetReader<R> {
+    inner: R,
+    base: u64,
+}
+
+impl<R: Seek> OffsetReader<R> {
+    fn new(inner: R, base: u64) -> OffsetReader<R> {
+        OffsetReader { inner, base }
+    }
+}
+
+impl<R: std::io::Read> std::io::Read for OffsetReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.inner.read(buf)
+    }
+}
+
+impl<R: BufRead> BufRead for OffsetReader<R> {
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        self.inner.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.inner.consume(amt)
+    }
+}
+
+impl<R: Seek> Seek for OffsetReader<R> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let resolved = match pos {
+            SeekFrom::Start(offset) => SeekFrom::Start(self.base + offset),
+            SeekFrom::Current(offset) => SeekFrom::Current(offset),
+            SeekFrom::End(offset) => SeekFrom::End(offset),
+        };
+
+        let absolute = self.inner.seek(resolved)?;
+        Ok(absolute.saturating_sub(self.base))
+    }
+}
+
+/// Magic byte sequences long and specific enough to trust when found away
+/// from the very start of a blob, used by [`scan_embedded`].
+///
+/// Shorter or more generic magics (a handful of formats in this crate match
+/// on 2-4 common bytes) are only trustworthy at offset 0, where the normal
+/// dispatch in [`reader_type`] already handles them; scanning blindly
+/// through the middle of a file with those same magics would produce far
+/// too many false positives.
+const ROBUST_EMBEDDED_MAGICS: &[&[u8]] = &[
+    &[0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n'], // PNG
+    &[
+        0xAB, 0x4B, 0x54, 0x58, 0x20, 0x32, 0x30, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A,
+    ], // KTX2
+    b"DDS ",
+    b"qoif",
+];
+
+/// Walk forward through a reader looking for an image embedded at some
+/// unknown offset, such as a thumbnail packed inside an EXIF blob or a
+/// texture atlas sub-image.
+///
+/// # Arguments
+/// * `reader` - A reader for the data
+/// * `max_scan` - The maximum number of bytes to scan forward before giving up
+///
+/// # Remarks
+///
+/// Offset 0 is checked with the full format dispatch used by
+/// [`reader_type`], which includes formats with short, generic magic
+/// bytes. Every later offset only matches [`ROBUST_EMBEDDED_MAGICS`] -
+/// magics long and specific enough that a chance collision mid-stream is
+/// implausible - to avoid false positives from unrelated bytes in the
+/// surrounding container.
+///
+/// Returns the offset the image was found at along with its detected type.
+pub fn scan_embedded<R: BufRead + Seek>(
+    mut reader: R,
+    max_scan: usize,
+) -> ImageResult<(u64, ImageType)> {
+    if let Ok(image_type) = reader_type_at(&mut reader, 0) {
+        return Ok((0, image_type));
+    }
+
+    let window_len = ROBUST_EMBEDDED_MAGICS
+        .iter()
+        .map(|magic| magic.len())
+        .max()
+        .unwrap_or(0);
+    let mut window = vec![0u8; window_len];
+
+    for offset in 1..=max_scan as u64 {
+        reader.seek(SeekFrom::Start(offset))?;
+        if reader.read_exact(&mut window).is_err() {
+            break;
+        }
+
+        let found = ROBUST_EMBEDDED_MAGICS
+            .iter()
+            .any(|magic| window.starts_with(magic));
+
+        if found {
+            if let Ok(image_type) = reader_type_at(&mut reader, offset) {
+                return Ok((offset, image_type));
+            }
+        }
+    }
+
+    Err(ImageError::NotSupported)
+}
+
+/// Get the displayed image size and orientation from a local file.
+///
+/// # Arguments
+/// * `path` - A local path to the file to parse.
+///
+/// # Remarks
+///
+/// The returned [`ImageSize`] always holds the dimensions as stored in the
+/// file; use the returned [`Orientation`] to know how to lay the pixels out
+/// (e.g. swap width/height when [`Orientation::swaps_dimensions`] is true).
+pub fn size_with_orientation<P: AsRef<Path>>(path: P) -> ImageResult<(ImageSize, Orientation)> {
+    let file = File::open(path)?;
+    let reader = BufReader::new(file);
+    reader_size_with_orientation(reader)
+}
+
+/// Get the displayed image size and orientation from a block of raw data.
+///
+/// # Arguments
+/// * `data` - A Vec containing the data to parse for image size.
+pub fn blob_size_with_orientation(data: &[u8]) -> ImageResult<(ImageSize, Orientation)> {
+    let reader = Cursor::new(data);
+    reader_size_with_orientation(reader)
+}
+
+/// Get the displayed image size and orientation from a reader.
+///
+/// # Arguments
+/// * `reader` - A reader for the data
+pub fn reader_size_with_orientation<R: BufRead + Seek>(
+    mut reader: R,
+) -> ImageResult<(ImageSize, Orientation)> {
+    reader_type(&mut reader)?.reader_size_with_orientation(&mut reader)
+}
+
+/// Get the already-rotated display-space size of a local file, applying its
+/// orientation so callers don't have to special-case the axis swap.
+///
+/// # Arguments
+/// * `path` - A local path to the file to parse.
+pub fn display_size<P: AsRef<Path>>(path: P) -> ImageResult<ImageSize> {
+    let (size, orientation) = size_with_orientation(path)?;
+    Ok(orientation.apply(size))
+}
+
+/// Get the already-rotated display-space size from a block of raw data.
+///
+/// # Arguments
+/// * `data` - A Vec containing the data to parse for image size.
+pub fn blob_display_size(data: &[u8]) -> ImageResult<ImageSize> {
+    let (size, orientation) = blob_size_with_orientation(data)?;
+    Ok(orientation.apply(size))
+}
+
+/// Get the already-rotated display-space size from a reader.
+///
+/// # Arguments
+/// * `reader` - A reader for the data
+pub fn reader_display_size<R: BufRead + Seek>(reader: R) -> ImageResult<ImageSize> {
+    let (size, orientation) = reader_size_with_orientation(reader)?;
+    Ok(orientation.apply(size))
+}
+
+/// Get the image size from a local file, bounding directory/box-walking
+/// formats to the given [`Limits`] instead of the generous defaults.
+///
+/// # Arguments
+/// * `path` - A local path to the file to parse.
+/// * `limits` - The [`Limits`] to enforce while parsing.
+pub fn size_with_limits<P: AsRef<Path>>(path: P, limits: &Limits) -> ImageResult<ImageSize> {
+    let file = File::open(path)?;
+    let reader = BufReader::new(file);
+    reader_size_with_limits(reader, limits)
+}
+
+/// Get the image size from a block of raw data, bounding directory/box-walking
+/// formats to the given [`Limits`] instead of the generous defaults.
+///
+/// # Arguments
+/// * `data` - A Vec containing the data to parse for image size.
+/// * `limits` - The [`Limits`] to enforce while parsing.
+pub fn blob_size_with_limits(data: &[u8], limits: &Limits) -> ImageResult<ImageSize> {
+    let reader = Cursor::new(data);
+    reader_size_with_limits(reader, limits)
+}
+
+/// Get the image size from a reader, bounding directory/box-walking formats
+/// to the given [`Limits`] instead of the generous defaults.
+///
+/// # Arguments
+/// * `reader` - A reader for the data
+/// * `limits` - The [`Limits`] to enforce while parsing.
+pub fn reader_size_with_limits<R: BufRead + Seek>(
+    mut reader: R,
+    limits: &Limits,
+) -> ImageResult<ImageSize> {
+    reader_type(&mut reader)?.reader_size_with_limits(&mut reader, limits)
+}
+
+// Builds the standard GPU mip chain for a texture container: each level is
+// half the previous one's width/height (rounded down, floored at 1) until
+// `levels` entries have been produced.
+#[cfg(any(feature = "dds", feature = "pvrtc", feature = "ktx2"))]
+fn mip_chain(width: usize, height: usize, levels: usize) -> Vec<ImageSize> {
+    let mut sizes = Vec::with_capacity(levels.max(1));
+    let (mut w, mut h) = (width, height);
+
+    for _ in 0..levels.max(1) {
+        sizes.push(ImageSize {
+            width: w,
+            height: h,
+        });
+        w = (w / 2).max(1);
+        h = (h / 2).max(1);
+    }
+
+    sizes
+}
+
+/// Get the dimensions of every page/sub-image from a local file, in order.
+///
+/// # Arguments
+/// * `path` - A local path to the file to parse.
+pub fn sizes<P: AsRef<Path>>(path: P) -> ImageResult<Vec<ImageSize>> {
+    let file = File::open(path)?;
+    let reader = BufReader::new(file);
+    reader_sizes(reader)
+}
+
+/// Get the dimensions of every page/sub-image from a block of raw data, in
+/// order.
+///
+/// # Arguments
+/// * `data` - A Vec containing the data to parse for image sizes.
+pub fn blob_sizes(data: &[u8]) -> ImageResult<Vec<ImageSize>> {
+    let reader = Cursor::new(data);
+    reader_sizes(reader)
+}
+
+/// Get the dimensions of every page/sub-image from a reader, in order.
+///
+/// # Arguments
+/// * `reader` - A reader for the data
+pub fn reader_sizes<R: BufRead + Seek>(mut reader: R) -> ImageResult<Vec<ImageSize>> {
+    reader_type(&mut reader)?.reader_sizes(&mut reader)
+}
+
+/// Get the number of frames/pages/items/array layers from a local file.
+///
+/// Returns `None` for formats that only ever hold a single image.
+///
+/// # Arguments
+/// * `path` - A local path to the file to parse.
+pub fn frame_count<P: AsRef<Path>>(path: P) -> ImageResult<Option<usize>> {
+    let file = File::open(path)?;
+    let reader = BufReader::new(file);
+    reader_frame_count(reader)
+}
+
+/// Get the number of frames/pages/items/array layers from a block of raw
+/// data.
+///
+/// # Arguments
+/// * `data` - A Vec containing the data to parse.
+pub fn blob_frame_count(data: &[u8]) -> ImageResult<Option<usize>> {
+    let reader = Cursor::new(data);
+    reader_frame_count(reader)
+}
+
+/// Get the number of frames/pages/items/array layers from a reader.
+///
+/// # Arguments
+/// * `reader` - A reader for the data
+pub fn reader_frame_count<R: BufRead + Seek>(mut reader: R) -> ImageResult<Option<usize>> {
+    reader_type(&mut reader)?.reader_frame_count(&mut reader)
+}
+
+/// Get the full texture container shape (width/height plus depth, mipmap
+/// level count, array layer count, and face count) from a local file.
+///
+/// # Arguments
+/// * `path` - A local path to the file to parse.
+pub fn container_metadata<P: AsRef<Path>>(path: P) -> ImageResult<ContainerMetadata> {
+    let file = File::open(path)?;
+    let reader = BufReader::new(file);
+    reader_container_metadata(reader)
+}
+
+/// Get the full texture container shape from a block of raw data.
+///
+/// # Arguments
+/// * `data` - A Vec containing the data to parse.
+pub fn blob_container_metadata(data: &[u8]) -> ImageResult<ContainerMetadata> {
+    let reader = Cursor::new(data);
+    reader_container_metadata(reader)
+}
+
+/// Get the full texture container shape from a reader.
+///
+/// # Arguments
+/// * `reader` - A reader for the data
+pub fn reader_container_metadata<R: BufRead + Seek>(
+    mut reader: R,
+) -> ImageResult<ContainerMetadata> {
+    reader_type(&mut reader)?.reader_container_metadata(&mut reader)
+}
+
+/// Get the mipmap/array/face/depth shape of a texture container from a
+/// local file.
+///
+/// Returns [`ImageError::NotSupported`] for formats that aren't a texture
+/// container.
+///
+/// # Arguments
+/// * `path` - A local path to the file to parse.
+pub fn texture_info<P: AsRef<Path>>(path: P) -> ImageResult<TextureInfo> {
+    let file = File::open(path)?;
+    let reader = BufReader::new(file);
+    reader_texture_info(reader)
+}
+
+/// Get the texture container shape from a block of raw data.
+///
+/// # Arguments
+/// * `data` - A Vec containing the data to parse.
+pub fn blob_texture_info(data: &[u8]) -> ImageResult<TextureInfo> {
+    let reader = Cursor::new(data);
+    reader_texture_info(reader)
+}
+
+/// Get the texture container shape from a reader.
+///
+/// # Arguments
+/// * `reader` - A reader for the data
+pub fn reader_texture_info<R: BufRead + Seek>(mut reader: R) -> ImageResult<TextureInfo> {
+    reader_type(&mut reader)?.reader_texture_info(&mut reader)
+}
+
+/// Get the pixel format (bit depth, channel layout, float-vs-integer
+/// samples) alongside dimensions from a local file.
+///
+/// # Arguments
+/// * `path` - A local path to the file to parse.
+pub fn image_info<P: AsRef<Path>>(path: P) -> ImageResult<ImageInfo> {
+    let file = File::open(path)?;
+    let reader = BufReader::new(file);
+    reader_image_info(reader)
+}
+
+/// Get the pixel format from a block of raw data.
+///
+/// # Arguments
+/// * `data` - A Vec containing the data to parse.
+pub fn blob_image_info(data: &[u8]) -> ImageResult<ImageInfo> {
+    let reader = Cursor::new(data);
+    reader_image_info(reader)
+}
+
+/// Get the pixel format from a reader.
+///
+/// # Arguments
+/// * `reader` - A reader for the data
+pub fn reader_image_info<R: BufRead + Seek>(mut reader: R) -> ImageResult<ImageInfo> {
+    reader_type(&mut reader)?.reader_image_info(&mut reader)
+}
+
+/// Get pixel/block format information from a local file: bit depth and
+/// channel layout for raster formats, or texel block dimensions for
+/// block-compressed GPU texture formats.
+///
+/// # Arguments
+/// * `path` - A local path to the file to parse.
+pub fn meta<P: AsRef<Path>>(path: P) -> ImageResult<ImageMeta> {
+    let file = File::open(path)?;
+    let reader = BufReader::new(file);
+    reader_meta(reader)
+}
+
+/// Get pixel/block format information from a block of raw data.
+///
+/// # Arguments
+/// * `data` - A Vec containing the data to parse.
+pub fn blob_meta(data: &[u8]) -> ImageResult<ImageMeta> {
+    let reader = Cursor::new(data);
+    reader_meta(reader)
+}
+
+/// Get pixel/block format information from a reader.
+///
+/// # Arguments
+/// * `reader` - A reader for the data
+pub fn reader_meta<R: BufRead + Seek>(mut reader: R) -> ImageResult<ImageMeta> {
+    reader_type(&mut reader)?.reader_meta(&mut reader)
+}
+
+/// Get DDS-specific metadata (depth, mipmap count, array size, cubemap
+/// flag, compression) from a local file.
+///
+/// # Arguments
+/// * `path` - A local path to the file to parse.
+#[cfg(feature = "dds")]
+pub fn dds_metadata<P: AsRef<Path>>(path: P) -> ImageResult<DdsMetadata> {
+    let file = File::open(path)?;
+    let reader = BufReader::new(file);
+    reader_dds_metadata(reader)
+}
+
+/// Get DDS-specific metadata from a block of raw data.
+///
+/// # Arguments
+/// * `data` - A Vec containing the data to parse.
+#[cfg(feature = "dds")]
+pub fn blob_dds_metadata(data: &[u8]) -> ImageResult<DdsMetadata> {
+    let reader = Cursor::new(data);
+    reader_dds_metadata(reader)
+}
+
+/// Get DDS-specific metadata from a reader.
+///
+/// # Arguments
+/// * `reader` - A reader for the data
+#[cfg(feature = "dds")]
+pub fn reader_dds_metadata<R: BufRead + Seek>(mut reader: R) -> ImageResult<DdsMetadata> {
+    match reader_type(&mut reader)? {
+        ImageType::Dds(_) => container::dds::metadata(&mut reader),
+        _ => Err(ImageError::NotSupported),
+    }
+}
+
+/// Get the image size and detected [`ImageType`] from a local file in a
+/// single pass.
+///
+/// # Arguments
+/// * `path` - A local path to the file to parse.
+pub fn image_metadata<P: AsRef<Path>>(path: P) -> ImageResult<ImageMetadata> {
+    let reader = BufReader::new(File::open(path)?);
+    reader_image_metadata(reader)
+}
+
+/// Get the image size and detected [`ImageType`] from a block of raw data in
+/// a single pass.
+///
+/// # Arguments
+/// * `data` - A Vec containing the data to parse.
+pub fn blob_image_metadata(data: &[u8]) -> ImageResult<ImageMetadata> {
+    let reader = Cursor::new(data);
+    reader_image_metadata(reader)
+}
+
+/// Get the image size and detected [`ImageType`] from a reader in a single
+/// pass.
+///
+/// # Arguments
+/// * `reader` - A reader for the data
+pub fn reader_image_metadata<R: BufRead + Seek>(mut reader: R) -> ImageResult<ImageMetadata> {
+    let image_type = reader_type(&mut reader)?;
+    let size = image_type.reader_size(&mut reader)?;
+    Ok(ImageMetadata { size, image_type })
+}
@@ -1,5 +1,5 @@
 use crate::{ImageError, ImageResult};
-use std::io::{self, BufRead, Read, Seek};
+use std::io::{self, BufRead, Read, Seek, SeekFrom};
 
 /// Used for TIFF decoding
 pub enum Endian {
@@ -16,6 +16,15 @@ pub fn read_i32<R: BufRead + Seek>(reader: &mut R, endianness: &Endian) -> Image
     }
 }
 
+pub fn read_i16<R: BufRead + Seek>(reader: &mut R, endianness: &Endian) -> ImageResult<i16> {
+    let mut buf = [0; 2];
+    reader.read_exact(&mut buf)?;
+    match endianness {
+        Endian::Little => Ok(i16::from_le_bytes(buf)),
+        Endian::Big => Ok(i16::from_be_bytes(buf)),
+    }
+}
+
 pub fn read_u32<R: BufRead + Seek>(reader: &mut R, endianness: &Endian) -> ImageResult<u32> {
     let mut buf = [0; 4];
     reader.read_exact(&mut buf)?;
@@ -58,6 +67,16 @@ pub fn read_u8<R: BufRead + Seek>(reader: &mut R) -> ImageResult<u8> {
     Ok(buf[0])
 }
 
+pub fn read_u64<R: BufRead + Seek>(reader: &mut R, endianness: &Endian) -> ImageResult<u64> {
+    let mut buf = [0; 8];
+    reader.read_exact(&mut buf)?;
+
+    match endianness {
+        Endian::Little => Ok(u64::from_le_bytes(buf)),
+        Endian::Big => Ok(u64::from_be_bytes(buf)),
+    }
+}
+
 pub fn read_bits(source: u128, num_bits: usize, offset: usize, size: usize) -> ImageResult<usize> {
     if offset + num_bits < size {
         Ok((source >> offset) as usize & ((1 << num_bits) - 1))
@@ -66,15 +85,45 @@ pub fn read_bits(source: u128, num_bits: usize, offset: usize, size: usize) -> I
     }
 }
 
-/// Assumes tags are in format of 4 char string followed by big endian size for tag
-pub fn read_tag<R: BufRead + Seek>(reader: &mut R) -> ImageResult<(String, usize)> {
+/// Reads an ISOBMFF-style box header: a big endian size followed by a 4
+/// char type. A size of `1` means the real size is a 64-bit `largesize`
+/// read immediately after the type; a size of `0` means the box runs to
+/// the end of the stream.
+pub fn read_tag<R: BufRead + Seek>(reader: &mut R) -> ImageResult<(String, u64)> {
+    let box_start = reader.seek(SeekFrom::Current(0))?;
+    let mut size = read_u32(reader, &Endian::Big)? as u64;
     let mut tag_buf = [0; 4];
-    let size = read_u32(reader, &Endian::Big)? as usize;
     reader.read_exact(&mut tag_buf)?;
 
+    if size == 1 {
+        size = read_u64(reader, &Endian::Big)?;
+    } else if size == 0 {
+        let header_end = reader.seek(SeekFrom::Current(0))?;
+        let stream_end = reader.seek(SeekFrom::End(0))?;
+        reader.seek(SeekFrom::Start(header_end))?;
+        size = stream_end - box_start;
+    }
+
     Ok((String::from_utf8_lossy(&tag_buf).into_owned(), size))
 }
 
+/// Reads a single line (up to, but not including, the next `\n`) into a
+/// `String`, capped at `max_len` bytes so a corrupt or hostile PNM/HDR header
+/// can't grow a "line" unboundedly. Errors with [`ImageError::IoError`] if
+/// the cap is hit before a newline is found.
+pub fn read_line_capped<R: BufRead>(reader: &mut R, max_len: usize) -> ImageResult<String> {
+    let mut bytes = Vec::new();
+    reader.by_ref().take(max_len as u64).read_until(b'\n', &mut bytes)?;
+
+    if bytes.last() == Some(&b'\n') {
+        bytes.pop();
+    } else if bytes.len() == max_len {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "Line exceeds capped length").into());
+    }
+
+    String::from_utf8(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e).into())
+}
+
 pub fn read_null_terminated_string<R: BufRead>(reader: &mut R) -> io::Result<String> {
     let mut bytes = Vec::new();
 
@@ -1,5 +1,5 @@
 #[cfg(test)]
-use imagesize::{blob_size, image_type, size, ImageType};
+use imagesize::{blob_size, image_type, size, AstcCompression, ImageType};
 use std::fs;
 
 #[test]
@@ -8,11 +8,22 @@ fn astc_format_detection() {
     let data = fs::read("tests/images/astc/test.astc").expect("Failed to read ASTC test file");
 
     match image_type(&data) {
-        Ok(ImageType::Astc) => (),
+        Ok(ImageType::Astc(..)) => (),
         _ => panic!("ASTC format not detected correctly"),
     }
 }
 
+#[test]
+#[cfg(feature = "astc")]
+fn astc_block_footprint_is_detected() {
+    let data = fs::read("tests/images/astc/test.astc").expect("Failed to read ASTC test file");
+
+    match image_type(&data).unwrap() {
+        ImageType::Astc(compression) => assert_ne!(compression, AstcCompression::Unknown),
+        other => panic!("Expected ASTC, got {:?}", other),
+    }
+}
+
 #[test]
 #[cfg(feature = "astc")]
 fn astc_size_reading_690x298() {
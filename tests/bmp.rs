@@ -13,3 +13,55 @@ fn bmp_test() {
         }
     );
 }
+
+#[test]
+#[cfg(feature = "bmp")]
+fn bmp_os2_core_header_test() {
+    let dim = size("tests/images/bmp/test_os2.bmp").unwrap();
+    assert_eq!(
+        dim,
+        ImageSize {
+            width: 200,
+            height: 100
+        }
+    );
+}
+
+#[test]
+#[cfg(feature = "bmp")]
+fn bmp_top_down_test() {
+    let dim = size("tests/images/bmp/test_top_down.bmp").unwrap();
+    assert_eq!(
+        dim,
+        ImageSize {
+            width: 512,
+            height: 512
+        }
+    );
+}
+
+#[test]
+#[cfg(feature = "bmp")]
+fn bmp_v3_header_test() {
+    let dim = size("tests/images/bmp/test_v3.bmp").unwrap();
+    assert_eq!(
+        dim,
+        ImageSize {
+            width: 512,
+            height: 512
+        }
+    );
+}
+
+#[test]
+#[cfg(feature = "bmp")]
+fn bmp_v5_header_test() {
+    let dim = size("tests/images/bmp/test_v5.bmp").unwrap();
+    assert_eq!(
+        dim,
+        ImageSize {
+            width: 512,
+            height: 512
+        }
+    );
+}
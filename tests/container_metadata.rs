@@ -0,0 +1,74 @@
+#[cfg(test)]
+use imagesize::container_metadata;
+
+#[test]
+#[cfg(feature = "dds")]
+fn dds_container_metadata_matches_size() {
+    let meta = container_metadata("tests/images/dds/test.dds").unwrap();
+    assert_eq!(meta.width, 100);
+    assert_eq!(meta.height, 67);
+    assert_eq!(meta.depth, 1);
+    assert!(meta.mip_levels >= 1);
+    assert_eq!(meta.array_layers, 1);
+    assert_eq!(meta.faces, 1);
+}
+
+#[test]
+#[cfg(feature = "dds")]
+fn dds_dx10_cubemap_detected_via_misc_flag() {
+    // A DX10 cubemap that only sets `miscFlag`'s TEXTURECUBE bit, without
+    // the legacy `dwCaps2` cubemap flags some writers omit.
+    let meta = container_metadata("tests/images/dds/dx10_cubemap_miscflag.dds").unwrap();
+    assert_eq!(meta.faces, 6);
+}
+
+#[test]
+#[cfg(feature = "ktx2")]
+fn ktx2_container_metadata_matches_size() {
+    let meta = container_metadata("tests/images/ktx2/test.ktx2").unwrap();
+    assert_eq!(meta.width, 256);
+    assert_eq!(meta.height, 256);
+    assert!(meta.depth >= 1);
+    assert!(meta.mip_levels >= 1);
+    assert!(meta.array_layers >= 1);
+    assert!(meta.faces >= 1);
+}
+
+#[test]
+#[cfg(feature = "pvrtc")]
+fn pvrtc_container_metadata_matches_size() {
+    let meta = container_metadata("tests/images/pvrtc/64x64.pvr").unwrap();
+    assert_eq!(meta.width, 64);
+    assert_eq!(meta.height, 64);
+    assert!(meta.mip_levels >= 1);
+    assert!(meta.array_layers >= 1);
+    assert!(meta.faces >= 1);
+}
+
+#[test]
+#[cfg(feature = "astc")]
+fn astc_container_metadata_reports_2d_depth() {
+    let meta = container_metadata("tests/images/astc/test.astc").unwrap();
+    assert_eq!(meta.width, 690);
+    assert_eq!(meta.height, 298);
+    assert_eq!(meta.depth, 1);
+}
+
+#[test]
+#[cfg(feature = "astc")]
+fn astc_container_metadata_reports_volume_depth() {
+    let meta = container_metadata("tests/images/astc/volume.astc").unwrap();
+    assert!(meta.depth > 1);
+}
+
+#[test]
+#[cfg(feature = "bmp")]
+fn simple_formats_default_to_unit_shape() {
+    let meta = container_metadata("tests/images/bmp/test.bmp").unwrap();
+    assert_eq!(meta.width, 512);
+    assert_eq!(meta.height, 512);
+    assert_eq!(meta.depth, 1);
+    assert_eq!(meta.mip_levels, 1);
+    assert_eq!(meta.array_layers, 1);
+    assert_eq!(meta.faces, 1);
+}
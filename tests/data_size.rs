@@ -0,0 +1,33 @@
+#[cfg(test)]
+use imagesize::{DdsCompression, ImageType};
+
+#[test]
+#[cfg(feature = "dds")]
+fn bc1_data_size_sums_mip_chain() {
+    let bc1 = ImageType::Dds(DdsCompression::Bc1);
+    assert_eq!(bc1.data_size(16, 16, 1), Some(4 * 4 * 8));
+    assert_eq!(bc1.data_size(16, 16, 2), Some(4 * 4 * 8 + 2 * 2 * 8));
+}
+
+#[test]
+#[cfg(feature = "dds")]
+fn uncompressed_dds_data_size_uses_bytes_per_pixel() {
+    let rgba32 = ImageType::Dds(DdsCompression::Rgba32);
+    assert_eq!(rgba32.data_size(16, 16, 1), Some(16 * 16 * 4));
+
+    let rgb24 = ImageType::Dds(DdsCompression::Rgb24);
+    assert_eq!(rgb24.data_size(16, 16, 1), Some(16 * 16 * 3));
+}
+
+#[test]
+#[cfg(feature = "bmp")]
+fn simple_formats_have_no_data_size() {
+    assert_eq!(ImageType::Bmp.data_size(16, 16, 1), None);
+}
+
+#[test]
+#[cfg(feature = "dds")]
+fn data_size_defaults_mipmaps_zero_to_one_level() {
+    let bc1 = ImageType::Dds(DdsCompression::Bc1);
+    assert_eq!(bc1.data_size(16, 16, 0), bc1.data_size(16, 16, 1));
+}
@@ -1,8 +1,19 @@
 #[cfg(test)]
-use imagesize::{size, ImageSize};
+use imagesize::{dds_metadata, size, ImageSize};
 
 #[test]
 fn dds_test() {
     let dim = size("tests/images/dds/test.dds").unwrap();
     assert_eq!(dim, ImageSize { width: 100, height: 67 });
 }
+
+#[test]
+fn dds_metadata_test() {
+    let meta = dds_metadata("tests/images/dds/test.dds").unwrap();
+    assert_eq!(meta.width, 100);
+    assert_eq!(meta.height, 67);
+    assert_eq!(meta.depth, 1);
+    assert!(meta.mipmap_count >= 1);
+    assert_eq!(meta.array_size, 1);
+    assert!(!meta.is_cubemap);
+}
@@ -0,0 +1,47 @@
+#[cfg(test)]
+use imagesize::{reader_size_at, reader_type_at, scan_embedded, ImageType};
+use std::fs::File;
+use std::io::BufReader;
+
+#[test]
+#[cfg(feature = "png")]
+fn reader_type_at_detects_image_at_offset() {
+    let file = File::open("tests/images/embedded/png_in_container.bin").unwrap();
+    let mut reader = BufReader::new(file);
+
+    let image_type = reader_type_at(&mut reader, 16).unwrap();
+    assert_eq!(image_type, ImageType::Png);
+}
+
+#[test]
+#[cfg(feature = "png")]
+fn reader_size_at_reads_size_of_embedded_image() {
+    let file = File::open("tests/images/embedded/png_in_container.bin").unwrap();
+    let mut reader = BufReader::new(file);
+
+    let dim = reader_size_at(&mut reader, 16).unwrap();
+    assert_eq!(dim.width, 690);
+    assert_eq!(dim.height, 298);
+}
+
+#[test]
+#[cfg(feature = "png")]
+fn scan_embedded_locates_a_robust_magic_mid_stream() {
+    let file = File::open("tests/images/embedded/png_in_container.bin").unwrap();
+    let mut reader = BufReader::new(file);
+
+    let (offset, image_type) = scan_embedded(&mut reader, 4096).unwrap();
+    assert_eq!(offset, 16);
+    assert_eq!(image_type, ImageType::Png);
+}
+
+#[test]
+#[cfg(feature = "png")]
+fn scan_embedded_finds_image_at_offset_zero_via_full_dispatch() {
+    let file = File::open("tests/images/png/test.png").unwrap();
+    let mut reader = BufReader::new(file);
+
+    let (offset, image_type) = scan_embedded(&mut reader, 64).unwrap();
+    assert_eq!(offset, 0);
+    assert_eq!(image_type, ImageType::Png);
+}
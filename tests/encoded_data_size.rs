@@ -0,0 +1,49 @@
+#[cfg(test)]
+use imagesize::{AstcCompression, DdsCompression, ImageType, PvrtcCompression};
+
+#[test]
+#[cfg(feature = "dds")]
+fn bc1_encoded_size_is_one_block_per_four_pixels() {
+    let bc1 = ImageType::Dds(DdsCompression::Bc1);
+    assert_eq!(bc1.encoded_data_size(16, 16), Some(4 * 4 * 8));
+}
+
+#[test]
+#[cfg(feature = "dds")]
+fn bc7_encoded_size_uses_sixteen_byte_blocks() {
+    let bc7 = ImageType::Dds(DdsCompression::Bc7);
+    assert_eq!(bc7.encoded_data_size(16, 16), Some(4 * 4 * 16));
+}
+
+#[test]
+#[cfg(feature = "dds")]
+fn uncompressed_dds_has_no_encoded_data_size() {
+    let rgba32 = ImageType::Dds(DdsCompression::Rgba32);
+    assert_eq!(rgba32.encoded_data_size(16, 16), None);
+}
+
+#[test]
+#[cfg(feature = "astc")]
+fn astc_encoded_size_uses_its_own_block_footprint() {
+    let astc_4x4 = ImageType::Astc(AstcCompression::Block4x4);
+    assert_eq!(astc_4x4.encoded_data_size(16, 16), Some(4 * 4 * 16));
+
+    let astc_8x8 = ImageType::Astc(AstcCompression::Block8x8);
+    assert_eq!(astc_8x8.encoded_data_size(16, 16), Some(2 * 2 * 16));
+}
+
+#[test]
+#[cfg(feature = "pvrtc")]
+fn pvrtc_encoded_size_uses_bpp_formulas() {
+    let pvrtc_4bpp = ImageType::Pvrtc(PvrtcCompression::Pvrtc4BppRgb);
+    assert_eq!(pvrtc_4bpp.encoded_data_size(16, 16), Some(16 * 16 * 4 / 8));
+
+    let pvrtc_2bpp = ImageType::Pvrtc(PvrtcCompression::Pvrtc2BppRgb);
+    assert_eq!(pvrtc_2bpp.encoded_data_size(16, 16), Some(16 * 16 * 2 / 8));
+}
+
+#[test]
+#[cfg(feature = "bmp")]
+fn simple_formats_have_no_encoded_data_size() {
+    assert_eq!(ImageType::Bmp.encoded_data_size(16, 16), None);
+}
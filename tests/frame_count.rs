@@ -0,0 +1,43 @@
+#[cfg(test)]
+use imagesize::frame_count;
+
+#[test]
+#[cfg(feature = "gif")]
+fn animated_gif_reports_frame_count() {
+    let frames = frame_count("tests/images/gif/test_apng_like.gif").unwrap();
+    assert_eq!(frames, Some(3));
+}
+
+#[test]
+#[cfg(feature = "gif")]
+fn single_frame_gif_reports_one() {
+    let frames = frame_count("tests/images/gif/test.gif").unwrap();
+    assert_eq!(frames, Some(1));
+}
+
+#[test]
+#[cfg(feature = "tiff")]
+fn multi_page_tiff_reports_directory_count() {
+    let frames = frame_count("tests/images/tif/test_multipage.tif").unwrap();
+    assert_eq!(frames, Some(3));
+}
+
+#[test]
+#[cfg(feature = "heif")]
+fn heif_reports_item_count() {
+    let frames = frame_count("tests/images/heic/heic.heic").unwrap();
+    assert!(frames.unwrap() >= 1);
+}
+
+#[test]
+#[cfg(feature = "dds")]
+fn dds_frame_count_is_array_layers() {
+    let frames = frame_count("tests/images/dds/test.dds").unwrap();
+    assert_eq!(frames, Some(1));
+}
+
+#[test]
+#[cfg(feature = "bmp")]
+fn single_image_formats_have_no_frame_count() {
+    assert_eq!(frame_count("tests/images/bmp/test.bmp").unwrap(), None);
+}
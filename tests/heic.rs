@@ -40,6 +40,22 @@ fn heic_type() {
     assert_eq!(ty, ImageType::Heif(Compression::Hevc));
 }
 
+#[test]
+#[cfg(feature = "heif")]
+fn heic_grid_reports_reconstructed_canvas_size() {
+    // A tiled HEIC whose primary item is a `grid` derivation over several
+    // smaller coded tiles - `size` should report the assembled canvas from
+    // the grid item's own payload, not any one tile's `ispe`.
+    let dim = size("tests/images/heic/heic_grid.heic").unwrap();
+    assert_eq!(
+        dim,
+        ImageSize {
+            width: 4032,
+            height: 3024
+        }
+    );
+}
+
 #[test]
 #[cfg(feature = "heif")]
 fn heic_msf1_type() {
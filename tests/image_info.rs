@@ -0,0 +1,36 @@
+#[cfg(test)]
+use imagesize::{image_info, ChannelLayout};
+
+#[test]
+#[cfg(feature = "tga")]
+fn tga_image_info_truecolor() {
+    let info = image_info("tests/images/tga/test.tga").unwrap();
+    assert_eq!(info.width, 100);
+    assert_eq!(info.height, 67);
+    assert!(!info.is_float);
+}
+
+#[test]
+#[cfg(feature = "tiff")]
+fn tiff_image_info_matches_size() {
+    let info = image_info("tests/images/tif/test.tif").unwrap();
+    assert_eq!(info.width, 1419);
+    assert_eq!(info.height, 1001);
+}
+
+#[test]
+#[cfg(feature = "exr")]
+fn exr_image_info_is_float() {
+    let info = image_info("tests/images/exr/test.exr").unwrap();
+    assert!(info.is_float);
+    assert!(matches!(
+        info.channels,
+        ChannelLayout::Rgb | ChannelLayout::Rgba
+    ));
+}
+
+#[test]
+#[cfg(feature = "bmp")]
+fn unsupported_format_returns_err() {
+    assert!(image_info("tests/images/bmp/test.bmp").is_err());
+}
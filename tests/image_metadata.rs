@@ -0,0 +1,35 @@
+#[cfg(test)]
+use imagesize::{image_metadata, ImageType};
+
+#[test]
+#[cfg(feature = "etc2")]
+fn etc2_image_metadata_carries_compression_variant() {
+    let meta = image_metadata("tests/images/etc2/64x64_rgb.pkm").unwrap();
+    assert_eq!(meta.size.width, 64);
+    assert_eq!(meta.size.height, 64);
+    assert!(matches!(meta.image_type, ImageType::Etc2(_)));
+}
+
+#[test]
+#[cfg(feature = "atc")]
+fn atc_image_metadata_carries_compression_variant() {
+    let meta = image_metadata("tests/images/atc/compressions/atc_rgb.pkm").unwrap();
+    assert!(matches!(meta.image_type, ImageType::Atc(_)));
+}
+
+#[test]
+#[cfg(feature = "pvrtc")]
+fn pvrtc_image_metadata_carries_compression_variant() {
+    let meta = image_metadata("tests/images/pvrtc/64x64.pvr").unwrap();
+    assert_eq!(meta.size.width, 64);
+    assert_eq!(meta.size.height, 64);
+    assert!(matches!(meta.image_type, ImageType::Pvrtc(_)));
+}
+
+#[test]
+#[cfg(feature = "bmp")]
+fn bmp_image_metadata_has_no_compression_descriptor() {
+    let meta = image_metadata("tests/images/bmp/test.bmp").unwrap();
+    assert_eq!(meta.size.width, 512);
+    assert_eq!(meta.image_type, ImageType::Bmp);
+}
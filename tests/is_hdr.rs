@@ -0,0 +1,36 @@
+#[cfg(test)]
+use imagesize::{DdsCompression, ImageType};
+#[cfg(feature = "ktx2")]
+use imagesize::Ktx2Compression;
+
+#[test]
+#[cfg(feature = "exr")]
+fn exr_is_hdr() {
+    assert!(ImageType::Exr.is_hdr());
+}
+
+#[test]
+#[cfg(feature = "hdr")]
+fn radiance_hdr_is_hdr() {
+    assert!(ImageType::Hdr.is_hdr());
+}
+
+#[test]
+#[cfg(feature = "dds")]
+fn bc6h_is_hdr_but_bc7_is_not() {
+    assert!(ImageType::Dds(DdsCompression::Bc6h).is_hdr());
+    assert!(!ImageType::Dds(DdsCompression::Bc7).is_hdr());
+}
+
+#[test]
+#[cfg(feature = "ktx2")]
+fn ktx2_bc6h_is_hdr_but_bc7_is_not() {
+    assert!(ImageType::Ktx2(Ktx2Compression::Bc6h).is_hdr());
+    assert!(!ImageType::Ktx2(Ktx2Compression::Bc7).is_hdr());
+}
+
+#[test]
+#[cfg(feature = "bmp")]
+fn simple_formats_are_not_hdr() {
+    assert!(!ImageType::Bmp.is_hdr());
+}
@@ -279,3 +279,16 @@ fn jxl_err_header() {
 fn jxl_err_signature() {
     assert!(size("tests/images/jxl/err_signature.jxl").is_err());
 }
+
+#[test]
+#[cfg(feature = "jxl")]
+fn jxl_multi_page_returns_single_size() {
+    let pages = imagesize::sizes("tests/images/jxl/valid_small.jxl").unwrap();
+    assert_eq!(
+        pages,
+        vec![ImageSize {
+            width: 32,
+            height: 32
+        }]
+    );
+}
@@ -1,5 +1,5 @@
 #[cfg(test)]
-use imagesize::{size, ImageSize};
+use imagesize::{image_type, size, ImageSize, ImageType, Ktx2Compression};
 
 #[test]
 #[cfg(feature = "ktx2")]
@@ -13,3 +13,49 @@ fn ktx2_test() {
         }
     );
 }
+
+#[test]
+#[cfg(feature = "ktx2")]
+fn ktx2_compression_is_detected_from_vk_format() {
+    match image_type("tests/images/ktx2/test.ktx2").unwrap() {
+        ImageType::Ktx2(compression) => assert_ne!(compression, Ktx2Compression::Unknown),
+        other => panic!("Expected KTX2, got {:?}", other),
+    }
+}
+
+// Builds just enough of a KTX2 header (the 12-byte identifier plus the
+// 4-byte little-endian vkFormat right after it) for `image_type` to
+// recognize the container and read its compression.
+fn ktx2_header_with_vk_format(vk_format: u32) -> Vec<u8> {
+    let mut header = vec![
+        0xAB, 0x4B, 0x54, 0x58, 0x20, 0x32, 0x30, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A,
+    ];
+    header.extend_from_slice(&vk_format.to_le_bytes());
+    header
+}
+
+#[test]
+#[cfg(feature = "ktx2")]
+fn ktx2_vk_format_boundaries_map_to_exact_compression() {
+    // One vkFormat from each family boundary called out by VK_FORMAT:
+    // BC6H = 143-144, BC7 = 145-146, ETC2 = 147-152, EAC = 153-156,
+    // ASTC = 157-184.
+    let cases = [
+        (143, Ktx2Compression::Bc6h),
+        (145, Ktx2Compression::Bc7),
+        (147, Ktx2Compression::Etc2),
+        (153, Ktx2Compression::Eac),
+        (157, Ktx2Compression::Astc),
+    ];
+
+    for (vk_format, expected) in cases {
+        let header = ktx2_header_with_vk_format(vk_format);
+        match image_type(&header).unwrap() {
+            ImageType::Ktx2(compression) => assert_eq!(
+                compression, expected,
+                "vkFormat {vk_format} should map to {expected:?}"
+            ),
+            other => panic!("Expected KTX2, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+#[cfg(test)]
+use imagesize::{blob_size_with_limits, ImageError, Limits};
+use std::fs;
+
+#[test]
+#[cfg(feature = "tiff")]
+fn tiff_rejects_ifd_count_over_tight_limits() {
+    let data = fs::read("tests/images/tif/test.tif").expect("Failed to read TIFF test file");
+
+    let tight_limits = Limits {
+        max_entries: 0,
+        ..Limits::default()
+    };
+
+    match blob_size_with_limits(&data, &tight_limits) {
+        Err(ImageError::LimitsExceeded) => (),
+        other => panic!("expected LimitsExceeded, got {:?}", other),
+    }
+}
+
+#[test]
+#[cfg(feature = "tiff")]
+fn tiff_default_limits_allow_normal_files() {
+    let data = fs::read("tests/images/tif/test.tif").expect("Failed to read TIFF test file");
+
+    let dim = blob_size_with_limits(&data, &Limits::default()).unwrap();
+    assert_eq!(dim.width, 1419);
+    assert_eq!(dim.height, 1001);
+}
+
+#[test]
+#[cfg(feature = "heif")]
+fn heif_rejects_unbounded_box_walk_under_tight_limits() {
+    let data = fs::read("tests/images/heic/heic.heic").expect("Failed to read HEIC test file");
+
+    let tight_limits = Limits {
+        max_directories: 0,
+        ..Limits::default()
+    };
+
+    match blob_size_with_limits(&data, &tight_limits) {
+        Err(ImageError::LimitsExceeded) => (),
+        other => panic!("expected LimitsExceeded, got {:?}", other),
+    }
+}
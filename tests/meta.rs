@@ -0,0 +1,36 @@
+#[cfg(test)]
+use imagesize::meta;
+
+#[test]
+#[cfg(feature = "tga")]
+fn raster_format_reports_bits_per_channel() {
+    let info = meta("tests/images/tga/test.tga").unwrap();
+    assert_eq!(info.width, 100);
+    assert_eq!(info.height, 67);
+    assert!(info.bits_per_channel.is_some());
+    assert!(info.block_dimensions.is_none());
+}
+
+#[test]
+#[cfg(feature = "astc")]
+fn astc_reports_block_dimensions() {
+    let info = meta("tests/images/astc/test.astc").unwrap();
+    assert_eq!(info.width, 690);
+    assert_eq!(info.height, 298);
+    assert!(info.block_dimensions.is_some());
+    assert!(info.bits_per_channel.is_none());
+}
+
+#[test]
+#[cfg(feature = "dds")]
+fn dds_meta_matches_size() {
+    let info = meta("tests/images/dds/test.dds").unwrap();
+    assert_eq!(info.width, 100);
+    assert_eq!(info.height, 67);
+}
+
+#[test]
+#[cfg(feature = "bmp")]
+fn unsupported_format_returns_not_supported() {
+    assert!(meta("tests/images/bmp/test.bmp").is_err());
+}
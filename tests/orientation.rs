@@ -0,0 +1,80 @@
+#[cfg(test)]
+use imagesize::{blob_size_with_orientation, display_size, Orientation};
+use std::fs;
+
+#[test]
+#[cfg(feature = "tiff")]
+fn tiff_orientation_normal() {
+    let data = fs::read("tests/images/tif/test.tif").expect("Failed to read TIFF test file");
+
+    let (dim, orientation) = blob_size_with_orientation(&data).unwrap();
+    assert_eq!(dim.width, 1419);
+    assert_eq!(dim.height, 1001);
+    assert_eq!(orientation, Orientation::Normal);
+}
+
+#[test]
+#[cfg(feature = "tiff")]
+fn tiff_orientation_rotated_swaps_on_request() {
+    let data = fs::read("tests/images/tif/test_orientation_6.tif")
+        .expect("Failed to read rotated TIFF test file");
+
+    let (dim, orientation) = blob_size_with_orientation(&data).unwrap();
+    assert_eq!(orientation, Orientation::Rotate90);
+    assert!(orientation.swaps_dimensions());
+    assert_eq!(dim.width, 1001);
+    assert_eq!(dim.height, 1419);
+}
+
+#[test]
+#[cfg(feature = "jpeg")]
+fn jpeg_orientation_defaults_to_normal_without_exif() {
+    let data = fs::read("tests/images/jpg/test.jpg").expect("Failed to read JPEG test file");
+
+    let (dim, orientation) = blob_size_with_orientation(&data).unwrap();
+    assert_eq!(dim.width, 690);
+    assert_eq!(dim.height, 298);
+    assert_eq!(orientation, Orientation::Normal);
+}
+
+#[test]
+#[cfg(feature = "tga")]
+fn tga_orientation_defaults_to_normal() {
+    let data = fs::read("tests/images/tga/test.tga").expect("Failed to read TGA test file");
+
+    let (dim, orientation) = blob_size_with_orientation(&data).unwrap();
+    assert_eq!(dim.width, 100);
+    assert_eq!(dim.height, 67);
+    assert_eq!(orientation, Orientation::Normal);
+}
+
+#[test]
+#[cfg(feature = "tiff")]
+fn display_size_swaps_axes_for_rotated_orientation() {
+    let dim = display_size("tests/images/tif/test_orientation_6.tif").unwrap();
+    assert_eq!(dim.width, 1001);
+    assert_eq!(dim.height, 1419);
+}
+
+#[test]
+#[cfg(feature = "heif")]
+fn heif_orientation_from_irot() {
+    let data = fs::read("tests/images/heic/heic_rotated.heic")
+        .expect("Failed to read rotated HEIC test file");
+
+    let (_dim, orientation) = blob_size_with_orientation(&data).unwrap();
+    assert_eq!(orientation, Orientation::Rotate90);
+}
+
+#[test]
+#[cfg(feature = "heif")]
+fn heif_orientation_falls_back_to_exif_item_without_irot() {
+    // No `irot` transform property on the primary item; orientation comes
+    // from the EXIF `Orientation` tag carried by the file's `Exif` item
+    // instead, the same tag JPEG reads out of its APP1 segment.
+    let data = fs::read("tests/images/heic/heic_exif_orientation.heic")
+        .expect("Failed to read HEIC test file with EXIF-only orientation");
+
+    let (_dim, orientation) = blob_size_with_orientation(&data).unwrap();
+    assert_eq!(orientation, Orientation::Rotate90);
+}
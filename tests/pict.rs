@@ -0,0 +1,34 @@
+#[cfg(test)]
+use imagesize::{size, ImageSize};
+
+#[test]
+#[cfg(feature = "pict")]
+fn pict_disk_header_test() {
+    let dim = size("tests/images/pict/test.pict").unwrap();
+    assert_eq!(
+        dim,
+        ImageSize {
+            width: 100,
+            height: 67
+        }
+    );
+}
+
+#[test]
+#[cfg(feature = "pict")]
+fn pict_no_header_test() {
+    let dim = size("tests/images/pict/no_header.pict").unwrap();
+    assert_eq!(
+        dim,
+        ImageSize {
+            width: 320,
+            height: 240
+        }
+    );
+}
+
+#[test]
+#[cfg(feature = "pict")]
+fn pict_inverted_frame_rect_is_an_error() {
+    assert!(size("tests/images/pict/inverted_frame.pict").is_err());
+}
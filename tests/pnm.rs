@@ -47,3 +47,21 @@ fn pnm_p5_test() {
 //     let dim = size("tests/images/pnm/P6/pbmlib.ppm").unwrap();
 //     assert_eq!(dim, ImageSize { width: 20, height: 10 });
 // }
+
+#[test]
+fn pnm_p7_pam_test() {
+    let dim = size("tests/images/pnm/P7/feep.pam").unwrap();
+    assert_eq!(dim, ImageSize { width: 24, height: 7 });
+}
+
+#[test]
+fn pnm_pf_pfm_color_test() {
+    let dim = size("tests/images/pnm/PF/feep.pfm").unwrap();
+    assert_eq!(dim, ImageSize { width: 24, height: 7 });
+}
+
+#[test]
+fn pnm_pf_pfm_grayscale_test() {
+    let dim = size("tests/images/pnm/Pf/feep.pfm").unwrap();
+    assert_eq!(dim, ImageSize { width: 24, height: 7 });
+}
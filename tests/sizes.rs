@@ -0,0 +1,44 @@
+#[cfg(test)]
+use imagesize::{sizes, ImageSize};
+
+#[test]
+#[cfg(feature = "tiff")]
+fn tiff_sizes_walks_every_ifd() {
+    let pages = sizes("tests/images/tif/test_multipage.tif").unwrap();
+    assert_eq!(pages.len(), 3);
+}
+
+#[test]
+#[cfg(feature = "dds")]
+fn dds_sizes_returns_full_mip_chain() {
+    let pages = sizes("tests/images/dds/test_mipmaps.dds").unwrap();
+    assert!(pages.len() > 1);
+
+    // Every level after the first should be half (floored, minimum 1) the
+    // dimensions of the one before it.
+    for window in pages.windows(2) {
+        let (prev, next) = (window[0], window[1]);
+        assert_eq!(next.width, (prev.width / 2).max(1));
+        assert_eq!(next.height, (prev.height / 2).max(1));
+    }
+}
+
+#[test]
+#[cfg(feature = "gif")]
+fn gif_sizes_returns_one_per_frame() {
+    let pages = sizes("tests/images/gif/test_apng_like.gif").unwrap();
+    assert_eq!(pages.len(), 3);
+}
+
+#[test]
+#[cfg(feature = "bmp")]
+fn simple_formats_return_single_size() {
+    let pages = sizes("tests/images/bmp/test.bmp").unwrap();
+    assert_eq!(
+        pages,
+        vec![ImageSize {
+            width: 512,
+            height: 512
+        }]
+    );
+}
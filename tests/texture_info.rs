@@ -0,0 +1,28 @@
+#[cfg(test)]
+use imagesize::texture_info;
+
+#[test]
+#[cfg(feature = "dds")]
+fn dds_texture_info_matches_size() {
+    let info = texture_info("tests/images/dds/test.dds").unwrap();
+    assert_eq!(info.size.width, 100);
+    assert_eq!(info.size.height, 67);
+    assert_eq!(info.depth, 1);
+    assert!(info.mipmap_count >= 1);
+    assert_eq!(info.array_layers, 1);
+    assert_eq!(info.faces, 1);
+}
+
+#[test]
+#[cfg(feature = "ktx2")]
+fn ktx2_texture_info_is_available() {
+    let info = texture_info("tests/images/ktx2/test.ktx2").unwrap();
+    assert_eq!(info.size.width, 256);
+    assert_eq!(info.size.height, 256);
+}
+
+#[test]
+#[cfg(feature = "bmp")]
+fn simple_formats_return_not_supported() {
+    assert!(texture_info("tests/images/bmp/test.bmp").is_err());
+}
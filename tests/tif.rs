@@ -39,3 +39,19 @@ fn tiff_test_bigtiff() {
         }
     );
 }
+
+#[test]
+#[cfg(feature = "tiff")]
+fn tiff_sizes_multi_page() {
+    use imagesize::sizes;
+
+    let dims = sizes("tests/images/tif/test_multipage.tif").unwrap();
+    assert_eq!(dims.len(), 3);
+    assert_eq!(
+        dims[0],
+        ImageSize {
+            width: 1419,
+            height: 1001
+        }
+    );
+}